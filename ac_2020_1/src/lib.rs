@@ -0,0 +1,29 @@
+use advent_of_code_util::{base_aoc, parse::read_lines};
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_lines(input_file)
+        .iter()
+        .map(|line| line.parse::<usize>().unwrap())
+        .collect::<Vec<usize>>();
+    let mut part_1_output = None;
+    for i in input.iter() {
+        for j in input.iter() {
+            if i + j == 2020 {
+                part_1_output = Some(i * j);
+            }
+        }
+    }
+    let mut part_2_output = None;
+    for i in input.iter() {
+        for j in input.iter() {
+            for k in input.iter() {
+                if i + j + k == 2020 {
+                    part_2_output = Some(i * j * k);
+                }
+            }
+        }
+    }
+    (part_1_output.unwrap(), part_2_output.unwrap())
+}
+
+base_aoc!(514579, 241861950);
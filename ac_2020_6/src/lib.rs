@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+use advent_of_code_util::{base_aoc, parse::read_blocks};
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_blocks(input_file);
+
+    let mut union_count = 0;
+    let mut intersection_count = 0;
+
+    for block in input {
+        let char_sets = block
+            .iter()
+            .map(|string| string.chars().collect::<HashSet<char>>())
+            .collect::<Vec<HashSet<char>>>();
+        union_count += char_sets
+            .clone()
+            .into_iter()
+            .reduce(|a, b| &a | &b)
+            .unwrap()
+            .len();
+        intersection_count += char_sets
+            .clone()
+            .into_iter()
+            .reduce(|a, b| &a & &b)
+            .unwrap()
+            .len();
+    }
+
+    (union_count, intersection_count)
+}
+
+base_aoc!(11, 6);
@@ -0,0 +1,57 @@
+use advent_of_code_util::{base_aoc, params::Param, parse::read_lines};
+
+const PREAMBLE_SIZE: Param<usize> = Param::new("preamble_size", 25);
+
+fn is_valid(num: usize, slice: &[usize]) -> bool {
+    for i in 0..slice.len() {
+        for j in 0..slice.len() {
+            if slice[i] + slice[j] == num {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let preamble_size = PREAMBLE_SIZE.get();
+    let input = read_lines(input_file)
+        .into_iter()
+        .map(|line| line.parse::<usize>().unwrap())
+        .collect::<Vec<usize>>();
+
+    let mut i = preamble_size;
+    loop {
+        if !is_valid(input[i], &input[i - preamble_size..=i]) {
+            break;
+        }
+        i += 1;
+    }
+    let first_invalid_num = input[i];
+
+    let mut sum_contiguous_set = 0;
+    for i in 0..input.len() {
+        let mut sum_so_far = 0;
+        let mut j = i;
+        while sum_so_far < first_invalid_num {
+            sum_so_far += input[j];
+            j += 1;
+        }
+        if sum_so_far == first_invalid_num && j - i >= 2 {
+            let mut contiguous_set = input[i..j].iter().clone().collect::<Vec<&usize>>();
+            contiguous_set.sort_unstable();
+            sum_contiguous_set =
+                **contiguous_set.first().unwrap() + **contiguous_set.last().unwrap();
+            break;
+        }
+    }
+
+    (first_invalid_num, sum_contiguous_set)
+}
+
+base_aoc!(main(
+    "testinput",
+    params: [preamble_size = 5],
+    part_1: 127,
+    part_2: 62,
+));
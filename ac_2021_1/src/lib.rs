@@ -0,0 +1,30 @@
+use advent_of_code_util::{base_aoc, parse::read_lines};
+
+fn num_increases(numbers: &[u32]) -> u32 {
+    let mut prev = numbers[0];
+    let mut num_increases = 0;
+    for number in numbers {
+        if number > &prev {
+            num_increases += 1;
+        }
+        prev = *number;
+    }
+    num_increases
+}
+
+fn get_program_output(input_file: &str) -> (u32, u32) {
+    let lines = read_lines(input_file);
+    let numbers = lines
+        .iter()
+        .map(|line| line.parse::<u32>().unwrap())
+        .collect::<Vec<u32>>();
+    let num_individual_increases = num_increases(&numbers);
+    let mut sliding_window_numbers = vec![];
+    for i in 2..numbers.len() {
+        sliding_window_numbers.push(numbers[i - 2] + numbers[i - 1] + numbers[i])
+    }
+    let sliding_scale_increases = num_increases(&sliding_window_numbers);
+    (num_individual_increases, sliding_scale_increases)
+}
+
+base_aoc!(7, 5);
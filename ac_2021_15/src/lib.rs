@@ -0,0 +1,45 @@
+use advent_of_code_util::icoordinate::ICoordinate;
+use advent_of_code_util::{
+    base_aoc,
+    grid_view::GridView,
+    parse::read_digit_grid,
+    search::{a_star_with, manhattan, Dense},
+};
+
+fn find_path_with_lowest_risk(
+    risks: &impl GridView<Cell = usize>,
+    width: usize,
+    height: usize,
+) -> usize {
+    let goal = ICoordinate {
+        x: width as isize - 1,
+        y: height as isize - 1,
+    };
+    a_star_with(
+        Dense::new(width * height, |c: &ICoordinate| {
+            c.y as usize * width + c.x as usize
+        }),
+        [ICoordinate { x: 0, y: 0 }],
+        |&position| {
+            risks
+                .neighbours(position)
+                .map(|neighbour| (neighbour, risks.get(neighbour).unwrap()))
+        },
+        |&position| position == goal,
+        |position| manhattan(position, &goal),
+    )
+    .goal_cost()
+    .expect("Goal not reacheable")
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let grid = read_digit_grid(input_file);
+    let beeg_grid = grid.tiled_with(5, 5, |risk, tile| (risk + tile.x + tile.y - 1) % 9 + 1);
+
+    (
+        find_path_with_lowest_risk(&grid, grid.width(), grid.height()),
+        find_path_with_lowest_risk(&beeg_grid, beeg_grid.width(), beeg_grid.height()),
+    )
+}
+
+base_aoc!(40, 315);
@@ -0,0 +1,132 @@
+use advent_of_code_util::{base_aoc_ignore_tests, parse::read_lines};
+use itertools::Itertools;
+
+#[derive(Debug)]
+struct Vector {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Debug)]
+struct TargetArea {
+    start: Vector,
+    end: Vector,
+}
+impl TargetArea {
+    fn from_str(line: &str) -> Self {
+        let (start, end) = line
+            .split_whitespace()
+            .collect_tuple::<(&str, &str, &str, &str)>()
+            .map(|(_, _, x_str, y_str)| {
+                (
+                    {
+                        let mut chars = x_str.chars();
+                        chars.next();
+                        chars.next();
+                        chars.next_back();
+                        chars
+                            .collect::<String>()
+                            .split("..")
+                            .map(|num| num.parse::<isize>().unwrap())
+                            .collect_tuple::<(isize, isize)>()
+                            .map(|(x, y)| Vector { x, y })
+                            .unwrap()
+                    },
+                    {
+                        let mut chars = y_str.chars();
+                        chars.next();
+                        chars.next();
+                        chars
+                            .collect::<String>()
+                            .split("..")
+                            .map(|num| num.parse::<isize>().unwrap())
+                            .collect_tuple::<(isize, isize)>()
+                            .map(|(x, y)| Vector { x, y })
+                            .unwrap()
+                    },
+                )
+            })
+            .unwrap();
+        TargetArea { start, end }
+    }
+
+    fn contains(&self, coord: &Vector) -> bool {
+        (self.start.x..=self.start.y).contains(&coord.x)
+            && (self.end.x..=self.end.y).contains(&coord.y)
+    }
+}
+
+struct MovingCoordinate {
+    position: Vector,
+    velocity: Vector,
+}
+impl MovingCoordinate {
+    fn step(&mut self) {
+        self.position.x += self.velocity.x;
+        self.position.y += self.velocity.y;
+        match self.velocity.x.cmp(&0) {
+            std::cmp::Ordering::Less => self.velocity.x += 1,
+            std::cmp::Ordering::Equal => {}
+            std::cmp::Ordering::Greater => self.velocity.x -= 1,
+        }
+        self.velocity.y -= 1;
+    }
+
+    fn is_within(&self, target_area: &TargetArea) -> bool {
+        target_area.contains(&self.position)
+    }
+
+    fn can_intercept(&self, target_area: &TargetArea) -> bool {
+        !((self.position.x > target_area.start.y && self.velocity.x >= 0)
+            || (self.position.x < target_area.start.x && self.velocity.x <= 0)
+            || (self.position.y < target_area.end.x && self.velocity.y <= 0))
+    }
+
+    fn highest_point(&mut self, target_area: &TargetArea) -> Result<isize, isize> {
+        let mut highest_point = self.position.y;
+        let mut did_intersect = false;
+        if self.is_within(target_area) {
+            did_intersect = true;
+        }
+        while self.can_intercept(target_area) || self.velocity.y > 0 {
+            self.step();
+            highest_point = std::cmp::max(highest_point, self.position.y);
+            if self.is_within(target_area) {
+                did_intersect = true;
+            }
+        }
+        if did_intersect {
+            Ok(highest_point)
+        } else {
+            Err(highest_point)
+        }
+    }
+}
+
+fn get_program_output(input_file: &str) -> (isize, isize) {
+    let input = read_lines(input_file);
+
+    let target_area = TargetArea::from_str(&input[0]);
+
+    let mut highest_so_far = isize::MIN;
+    let mut num_intercepts = 0;
+    for starting_x_velocity in -300..=300 {
+        for starting_y_velocity in -300..=300 {
+            let mut mc = MovingCoordinate {
+                position: Vector { x: 0, y: 0 },
+                velocity: Vector {
+                    x: starting_x_velocity,
+                    y: starting_y_velocity,
+                },
+            };
+            if let Ok(val) = mc.highest_point(&target_area) {
+                num_intercepts += 1;
+                highest_so_far = std::cmp::max(highest_so_far, val);
+            }
+        }
+    }
+
+    (highest_so_far, num_intercepts)
+}
+
+base_aoc_ignore_tests!(45, 0);
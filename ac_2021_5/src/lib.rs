@@ -0,0 +1,109 @@
+use advent_of_code_util::{base_aoc, line::line, parse::read_lines, Coordinate};
+
+#[derive(Debug, Clone)]
+struct Line {
+    from: Coordinate,
+    to: Coordinate,
+}
+impl Line {
+    fn from_str(str: &str) -> Self {
+        let separated = str.split(" -> ").collect::<Vec<&str>>();
+        Line {
+            from: separated[0].parse().unwrap(),
+            to: separated[1].parse().unwrap(),
+        }
+    }
+    fn is_diagonal(&self) -> bool {
+        self.from.x != self.to.x && self.from.y != self.to.y
+    }
+    fn points(&self) -> impl Iterator<Item = Coordinate> {
+        line(self.from, self.to)
+    }
+}
+
+#[derive(Debug)]
+struct Diagram {
+    data: Vec<Vec<usize>>,
+}
+impl Diagram {
+    fn with_size(width: usize, height: usize) -> Self {
+        Diagram {
+            data: vec![vec![0; height]; width],
+        }
+    }
+    fn num_overlaps(&self) -> usize {
+        self.data
+            .iter()
+            .flat_map(|column| column.iter().filter(|space| **space > 1))
+            .count()
+    }
+    fn add_line(&mut self, line: &Line) {
+        line.points()
+            .for_each(|coord| self.data[coord.x][coord.y] += 1)
+    }
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let lines = read_lines(input_file)
+        .iter()
+        .map(|line| Line::from_str(line))
+        .collect::<Vec<Line>>();
+    let board_width = lines
+        .iter()
+        .map(|line| std::cmp::max(line.to.x, line.from.x))
+        .max()
+        .unwrap()
+        + 1;
+    let board_height = lines
+        .iter()
+        .map(|line| std::cmp::max(line.to.y, line.from.y))
+        .max()
+        .unwrap()
+        + 1;
+    let mut board = Diagram::with_size(board_width, board_height);
+
+    for line in lines.iter().filter(|line| !line.is_diagonal()) {
+        board.add_line(line);
+    }
+
+    let mut part_2_board = Diagram::with_size(board_width, board_height);
+    for line in lines {
+        part_2_board.add_line(&line);
+    }
+    (board.num_overlaps(), part_2_board.num_overlaps())
+}
+
+#[cfg(test)]
+mod other_tests {
+    use super::*;
+
+    mod line {
+        use super::*;
+
+        #[test]
+        fn is_diagonal() {
+            let line = Line {
+                from: Coordinate { x: 0, y: 0 },
+                to: Coordinate { x: 0, y: 1 },
+            };
+
+            assert!(!line.is_diagonal());
+
+            let line = Line {
+                from: Coordinate { x: 0, y: 0 },
+                to: Coordinate { x: 1, y: 1 },
+            };
+
+            assert!(line.is_diagonal());
+
+            let line = Line {
+                from: Coordinate { x: 0, y: 0 },
+                to: Coordinate { x: 1, y: 0 },
+            };
+
+            assert!(!line.is_diagonal());
+        }
+    }
+}
+
+base_aoc!(5, 12);
@@ -0,0 +1,42 @@
+use advent_of_code_util::{base_aoc, parse::read_lines};
+
+struct LanternfishPopulation {
+    pops: [usize; 9],
+}
+impl LanternfishPopulation {
+    fn from_str(string: &str) -> Self {
+        let mut new_population = LanternfishPopulation { pops: [0; 9] };
+        string
+            .split(',')
+            .map(|fish| fish.parse::<usize>().unwrap())
+            .for_each(|fish| new_population.pops[fish] += 1);
+        new_population
+    }
+    fn forward_1_day(&mut self) {
+        let new_fish = self.pops[0];
+        for i in 1..=8 {
+            self.pops[i - 1] = self.pops[i];
+        }
+        self.pops[6] += new_fish;
+        self.pops[8] = new_fish;
+    }
+    fn forward_n_days(&mut self, n: usize) {
+        for _ in 0..n {
+            self.forward_1_day();
+        }
+    }
+    fn total_population(&self) -> usize {
+        self.pops.iter().sum()
+    }
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let mut pop = LanternfishPopulation::from_str(&read_lines(input_file)[0]);
+    pop.forward_n_days(80);
+    let after_80_days = pop.total_population();
+    pop.forward_n_days(256 - 80);
+    let after_256_days = pop.total_population();
+    (after_80_days, after_256_days)
+}
+
+base_aoc!(5934, 26984457539);
@@ -0,0 +1,27 @@
+use advent_of_code_util::{
+    base_aoc, grid::Grid, parse::read_digit_grid, region::regions_where, Coordinate,
+};
+
+fn is_low_point(height_map: &Grid<usize>, coord: Coordinate) -> bool {
+    height_map
+        .neighbours(coord)
+        .all(|surrounding_coord| height_map[surrounding_coord] > height_map[coord])
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let height_map = read_digit_grid(input_file);
+    let risk_levels_sum = height_map
+        .coordinates()
+        .filter(|&coord| is_low_point(&height_map, coord))
+        .map(|coord| height_map[coord] + 1)
+        .sum();
+    let mut all_basins = regions_where(&height_map, |&height| height != 9)
+        .iter()
+        .map(|basin| basin.area())
+        .collect::<Vec<usize>>();
+    all_basins.sort();
+    let basin_size_product = all_basins.iter().rev().take(3).product();
+    (risk_levels_sum, basin_size_product)
+}
+
+base_aoc!(15, 1134);
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use advent_of_code_util::{base_aoc, parse::read_lines, search::bfs, Coordinate};
+use itertools::Itertools;
+
+fn connections(
+    nodes: &BTreeMap<Coordinate, usize>,
+    position: Coordinate,
+    width: usize,
+    height: usize,
+) -> Vec<Coordinate> {
+    position
+        .get_surrounding_non_diagonal_coordinates(width, height)
+        .into_iter()
+        .filter(|neighbor| *nodes.get(neighbor).unwrap() <= *nodes.get(&position).unwrap() + 1)
+        .collect_vec()
+}
+
+fn shortest_path_from_starting_coordinates(
+    nodes: &BTreeMap<Coordinate, usize>,
+    starting_coordinates: Vec<Coordinate>,
+    ending_coordinate: Coordinate,
+    width: usize,
+    height: usize,
+) -> usize {
+    bfs(
+        starting_coordinates,
+        |&position| connections(nodes, position, width, height),
+        |&position| position == ending_coordinate,
+    )
+    .goal_cost()
+    .unwrap()
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_lines(input_file);
+
+    let width = input[0].len();
+    let height = input.len();
+    let mut nodes: BTreeMap<Coordinate, usize> = BTreeMap::new();
+    let mut starting_position = None;
+    let mut ending_position = None;
+
+    for (y, row) in input.into_iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            let height = match c {
+                'S' => {
+                    starting_position = Some(Coordinate { x, y });
+                    'a' as usize
+                }
+                'E' => {
+                    ending_position = Some(Coordinate { x, y });
+                    'z' as usize
+                }
+                other_char => other_char as usize,
+            };
+            nodes.insert(Coordinate { x, y }, height);
+        }
+    }
+
+    let starting_position = starting_position.unwrap();
+    let ending_position = ending_position.unwrap();
+
+    let result_1 = shortest_path_from_starting_coordinates(
+        &nodes,
+        vec![starting_position],
+        ending_position,
+        width,
+        height,
+    );
+
+    let result_2 = shortest_path_from_starting_coordinates(
+        &nodes,
+        nodes
+            .iter()
+            .filter(|(_, height)| **height == 'a' as usize)
+            .map(|(pos, _)| pos)
+            .cloned()
+            .collect_vec(),
+        ending_position,
+        width,
+        height,
+    );
+
+    (result_1, result_2)
+}
+
+base_aoc!(31, 29);
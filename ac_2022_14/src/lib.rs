@@ -0,0 +1,97 @@
+use advent_of_code_util::{
+    base_aoc, line::polyline, parse::read_lines, sparse_grid::SparseGrid, Coordinate,
+};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Space {
+    Rock,
+    Sand,
+}
+
+/**
+Where the next unit of sand dropped from `from` comes to rest, or `None` if it falls past `max_y`.
+With a floor, everything at `floor` is rock.
+*/
+fn drop_sand(
+    grid: &SparseGrid<Coordinate, Space>,
+    from: Coordinate,
+    max_y: usize,
+    floor: Option<usize>,
+) -> Option<Coordinate> {
+    let is_empty = |coord: Coordinate| Some(coord.y) != floor && !grid.contains(coord);
+    let mut falling_sand = from;
+    while falling_sand.y <= max_y {
+        let below = Coordinate {
+            x: falling_sand.x,
+            y: falling_sand.y + 1,
+        };
+        falling_sand = if is_empty(below) {
+            below
+        } else if is_empty(Coordinate {
+            x: below.x - 1,
+            ..below
+        }) {
+            Coordinate {
+                x: below.x - 1,
+                ..below
+            }
+        } else if is_empty(Coordinate {
+            x: below.x + 1,
+            ..below
+        }) {
+            Coordinate {
+                x: below.x + 1,
+                ..below
+            }
+        } else {
+            return Some(falling_sand);
+        }
+    }
+    None
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let rocks = read_lines(input_file)
+        .into_iter()
+        .map(|line| {
+            line.split(" -> ")
+                .map(|s| s.parse::<Coordinate>().unwrap())
+                .collect_vec()
+        })
+        .flat_map(polyline)
+        .map(|rock| (rock, Space::Rock))
+        .collect::<SparseGrid<_, _>>();
+
+    let sand_spawn = Coordinate { x: 500, y: 0 };
+    let max_y = rocks.bounds().unwrap().1.y;
+
+    let count_sand = |grid: &SparseGrid<Coordinate, Space>| {
+        grid.values().filter(|&&space| space == Space::Sand).count()
+    };
+
+    let result_1 = {
+        let mut grid = rocks.clone();
+        while let Some(rested) = drop_sand(&grid, sand_spawn, max_y, None) {
+            grid.insert(rested, Space::Sand);
+        }
+
+        count_sand(&grid)
+    };
+
+    let result_2 = {
+        let floor = max_y + 2;
+        let mut grid = rocks;
+
+        while !grid.contains(sand_spawn) {
+            let rested = drop_sand(&grid, sand_spawn, floor, Some(floor)).unwrap();
+            grid.insert(rested, Space::Sand);
+        }
+
+        count_sand(&grid)
+    };
+
+    (result_1, result_2)
+}
+
+base_aoc!(24, 93);
@@ -0,0 +1,129 @@
+use std::collections::BTreeSet;
+
+use advent_of_code_util::{abs_diff, base_aoc, params::Param, parse::read_lines};
+use itertools::Itertools;
+use regex::Regex;
+
+const PART_1_Y: Param<isize> = Param::new("part_1_y", 2000000);
+const PART_2_BOUND: Param<isize> = Param::new("part_2_bound", 4000000);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Coordinate {
+    x: isize,
+    y: isize,
+}
+impl Coordinate {
+    pub fn manhatten_distance(&self, other: &Self) -> isize {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+}
+
+#[derive(Debug)]
+struct Sensor {
+    position: Coordinate,
+    beacon: Coordinate,
+}
+impl Sensor {
+    pub fn from_line(line: &str) -> Self {
+        let re = Regex::new(r"[xy]=(-?[0-9]+)").unwrap();
+        let mut caps = re.captures_iter(line);
+        Self {
+            position: Coordinate {
+                x: caps.next().unwrap()[1].parse().unwrap(),
+                y: caps.next().unwrap()[1].parse().unwrap(),
+            },
+            beacon: Coordinate {
+                x: caps.next().unwrap()[1].parse().unwrap(),
+                y: caps.next().unwrap()[1].parse().unwrap(),
+            },
+        }
+    }
+
+    // TODO - rename
+    pub fn range_intersects(&self, y: isize) -> Vec<Coordinate> {
+        let range = self.position.manhatten_distance(&self.beacon);
+
+        let x_radius = range - abs_diff(self.position.y, y);
+        let start_x = self.position.x - x_radius;
+        let end_x = self.position.x + x_radius;
+
+        (start_x..=end_x).map(|x| Coordinate { x, y }).collect_vec()
+    }
+
+    pub fn square_outside_boundary(&self) -> Vec<Coordinate> {
+        let distance_past_beacon = self.position.manhatten_distance(&self.beacon) + 1;
+
+        (0..distance_past_beacon)
+            .flat_map(|i| {
+                [
+                    Coordinate {
+                        x: self.position.x + i,
+                        y: self.position.y + (distance_past_beacon - i),
+                    },
+                    Coordinate {
+                        x: self.position.x + (distance_past_beacon - i),
+                        y: self.position.y - i,
+                    },
+                    Coordinate {
+                        x: self.position.x - i,
+                        y: self.position.y - (distance_past_beacon - i),
+                    },
+                    Coordinate {
+                        x: self.position.x - (distance_past_beacon - i),
+                        y: self.position.y + i,
+                    },
+                ]
+            })
+            .collect_vec()
+    }
+
+    pub fn is_inside_boundary(&self, coord: &Coordinate) -> bool {
+        let distance_to_beacon = self.position.manhatten_distance(&self.beacon);
+        let distance_to_point = self.position.manhatten_distance(coord);
+
+        distance_to_beacon >= distance_to_point
+    }
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let part_1_y = PART_1_Y.get();
+    let part_2_bound = PART_2_BOUND.get();
+    let input = read_lines(input_file)
+        .into_iter()
+        .map(|line| Sensor::from_line(&line))
+        .collect_vec();
+
+    let result_1 = input
+        .iter()
+        .flat_map(|sensor| sensor.range_intersects(part_1_y))
+        .collect::<BTreeSet<_>>()
+        .difference(&input.iter().map(|sensor| sensor.beacon.clone()).collect())
+        .count();
+
+    let result_2 = {
+        let all_possible_points = input
+            .iter()
+            .flat_map(|sensor| sensor.square_outside_boundary())
+            .unique()
+            .filter(|point| {
+                point.x >= 0 && point.y >= 0 && point.x <= part_2_bound && point.y <= part_2_bound
+            })
+            .filter(|point| !input.iter().any(|sensor| sensor.is_inside_boundary(point)))
+            .collect_vec();
+
+        assert_eq!(all_possible_points.len(), 1);
+
+        let only_position = &all_possible_points[0];
+
+        ((only_position.x * 4000000) + only_position.y) as usize
+    };
+
+    (result_1, result_2)
+}
+
+base_aoc!(main(
+    "testinput",
+    params: [part_1_y = 10, part_2_bound = 20],
+    part_1: 26,
+    part_2: 56000011,
+));
@@ -0,0 +1,68 @@
+use advent_of_code_util::{base_aoc, parse::read_lines};
+use itertools::Itertools;
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_lines(input_file);
+
+    // The part 2 example has lines without any digits, which part 1 ignores
+    let a = input
+        .iter()
+        .filter_map(|line| {
+            let numbers = line.chars().filter(|c| c.is_numeric()).collect_vec();
+            format!("{}{}", numbers.first()?, numbers.last()?)
+                .parse::<usize>()
+                .ok()
+        })
+        .sum::<usize>();
+
+    let b = input
+        .into_iter()
+        .map(|line| {
+            let mut numbers = Vec::new();
+
+            for i in 0..line.len() {
+                if line.chars().nth(i).unwrap().is_numeric() {
+                    numbers.push(line.chars().nth(i).unwrap().to_digit(10).unwrap());
+                }
+                if line[i..].starts_with("one") {
+                    numbers.push(1);
+                }
+                if line[i..].starts_with("two") {
+                    numbers.push(2);
+                }
+                if line[i..].starts_with("three") {
+                    numbers.push(3);
+                }
+                if line[i..].starts_with("four") {
+                    numbers.push(4);
+                }
+                if line[i..].starts_with("five") {
+                    numbers.push(5);
+                }
+                if line[i..].starts_with("six") {
+                    numbers.push(6);
+                }
+                if line[i..].starts_with("seven") {
+                    numbers.push(7);
+                }
+                if line[i..].starts_with("eight") {
+                    numbers.push(8);
+                }
+                if line[i..].starts_with("nine") {
+                    numbers.push(9);
+                }
+            }
+
+            format!("{}{}", numbers.first().unwrap(), numbers.last().unwrap())
+                .parse::<usize>()
+                .unwrap()
+        })
+        .sum::<usize>();
+
+    (a, b)
+}
+
+base_aoc!(
+    main("testinput", part_1: 142, part_2: 142),
+    part_2_example("testinput2", part_2: 281),
+);
@@ -0,0 +1,77 @@
+use advent_of_code_util::{base_aoc, grid::Grid, parse::read_blocks};
+use itertools::Itertools;
+
+fn is_horizontal_reflection(block: &Grid<char>, y: usize) -> bool {
+    let height = block.height();
+    let num_reflectable_units = (y + 1).min(height - (y + 1));
+    (0..num_reflectable_units)
+        .all(|y_offset| block.row(y - y_offset) == block.row(y + y_offset + 1))
+}
+fn is_vertical_reflection(block: &Grid<char>, x: usize) -> bool {
+    let width = block.width();
+    let num_reflectable_units = (x + 1).min(width - (x + 1));
+    (0..num_reflectable_units)
+        .all(|x_offset| block.column(x - x_offset) == block.column(x + x_offset + 1))
+}
+fn find_reflection_points(block: &Grid<char>) -> Vec<usize> {
+    let mut reflection_points = vec![];
+    for y in 0..block.height() - 1 {
+        if is_horizontal_reflection(block, y) {
+            reflection_points.push((y + 1) * 100);
+        }
+    }
+    for x in 0..block.width() - 1 {
+        if is_vertical_reflection(block, x) {
+            reflection_points.push(x + 1);
+        }
+    }
+    reflection_points
+}
+
+fn find_reflection_points_with_smudge(block: &Grid<char>) -> usize {
+    let original_reflection_points = *find_reflection_points(block).first().unwrap();
+    for coord in block.coordinates() {
+        let mut new_block = block.clone();
+        new_block[coord] = match new_block[coord] {
+            '#' => '.',
+            '.' => '#',
+            _ => panic!("Invalid block char"),
+        };
+        if let Some(points) = find_reflection_points(&new_block)
+            .into_iter()
+            .find(|points| *points != original_reflection_points)
+        {
+            return points;
+        }
+    }
+    panic!("No smudge points found");
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_blocks(input_file)
+        .into_iter()
+        .map(|block| {
+            Grid::try_from(
+                block
+                    .into_iter()
+                    .map(|line| line.chars().collect_vec())
+                    .collect_vec(),
+            )
+            .unwrap()
+        })
+        .collect_vec();
+
+    let result_1 = input
+        .iter()
+        .map(|block| *find_reflection_points(block).first().unwrap())
+        .sum::<usize>();
+
+    let result_2 = input
+        .iter()
+        .map(find_reflection_points_with_smudge)
+        .sum::<usize>();
+
+    (result_1, result_2)
+}
+
+base_aoc!(405, 400);
@@ -0,0 +1,83 @@
+use advent_of_code_util::{base_aoc, grid::Grid, parse::read_grid, Coordinate, Direction};
+use itertools::Itertools;
+
+/**
+Rolls every round rock as far up as it will go
+*/
+fn roll_up(platform: &mut Grid<char>) {
+    for x in 0..platform.width() {
+        let mut free = 0;
+        for y in 0..platform.height() {
+            match platform[Coordinate { x, y }] {
+                '#' => free = y + 1,
+                'O' => {
+                    platform[Coordinate { x, y }] = '.';
+                    platform[Coordinate { x, y: free }] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+type Turn = fn(&Grid<char>) -> Grid<char>;
+
+fn roll(platform: &mut Grid<char>, direction: Direction) {
+    // Turn the platform so `direction` is up, roll, then turn it back
+    let (turn, unturn): (Turn, Turn) = match direction {
+        Direction::Up => (Grid::clone, Grid::clone),
+        Direction::Right => (Grid::rotate_left, Grid::rotate_right),
+        Direction::Down => (Grid::rotate_180, Grid::rotate_180),
+        Direction::Left => (Grid::rotate_right, Grid::rotate_left),
+    };
+    let mut turned = turn(platform);
+    roll_up(&mut turned);
+    *platform = unturn(&turned);
+}
+
+fn calculate_north_load(platform: &Grid<char>) -> usize {
+    platform
+        .enumerate()
+        .filter(|(_, c)| **c == 'O')
+        .map(|(coord, _)| platform.height() - coord.y)
+        .sum()
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let mut input = read_grid(input_file);
+
+    let mut part_1_input = input.clone();
+
+    roll(&mut part_1_input, Direction::Up);
+
+    let result_1 = calculate_north_load(&part_1_input);
+
+    let mut seen_results = vec![];
+    while !seen_results.contains(&input) {
+        seen_results.push(input.clone());
+        roll(&mut input, Direction::Up);
+        roll(&mut input, Direction::Left);
+        roll(&mut input, Direction::Down);
+        roll(&mut input, Direction::Right);
+    }
+    let start_cycle_index = seen_results
+        .iter()
+        .find_position(|r| r == &&input)
+        .unwrap()
+        .0;
+    let cycle_length = seen_results.len() - start_cycle_index;
+    let offset = seen_results.len() - cycle_length;
+    let final_cycle_index = ((1_000_000_000 - start_cycle_index) % cycle_length) + offset;
+    let result_2 = calculate_north_load(&seen_results[final_cycle_index]);
+
+    // Solution: find long cycles between cycles. Create vec of all previous ones, check against that after things have settled. Find out how long between cycles,
+    // then use that info to calculate which portion of the cycle it'll be on after a billion cycles
+
+    // Use Map from vec -> cycle index?
+    // Upon first match, find cycle length. Final cycle index = ((1 billion - start of cycle index) % cycle length) + offset. Maybe an off-by-one error in that math somewhere.
+
+    (result_1, result_2)
+}
+
+base_aoc!(136, 64);
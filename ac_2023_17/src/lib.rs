@@ -0,0 +1,56 @@
+use advent_of_code_util::{
+    base_aoc, grid::Grid, parse::read_digit_grid, search::dijkstra, Coordinate, Direction,
+};
+
+fn get_shortest_path(grid: &Grid<usize>, min_step: usize, max_step: usize) -> usize {
+    let goal = Coordinate {
+        x: grid.width() - 1,
+        y: grid.height() - 1,
+    };
+
+    // The direction is how the crucible arrived, so it has to turn before moving again
+    let moves = |&(position, direction): &(Coordinate, Option<Direction>)| {
+        let mut moves = vec![];
+        for new_direction in Direction::iter() {
+            if direction.is_some_and(|direction| direction.turn_to(new_direction).is_none()) {
+                continue;
+            }
+            let mut new_position = position;
+            let mut next_cost = 0;
+            for dist in 1..=max_step {
+                match new_position
+                    .step(new_direction)
+                    .filter(|&c| grid.contains(c))
+                {
+                    Some(stepped) => new_position = stepped,
+                    None => break,
+                }
+                next_cost += grid[new_position];
+                if dist >= min_step {
+                    moves.push(((new_position, Some(new_direction)), next_cost));
+                }
+            }
+        }
+        moves
+    };
+
+    dijkstra(
+        [(Coordinate { x: 0, y: 0 }, None)],
+        moves,
+        |&(position, _)| position == goal,
+    )
+    .goal_cost()
+    .expect("Couldn't find a shortest path")
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_digit_grid(input_file);
+
+    let result_1 = get_shortest_path(&input, 1, 3);
+
+    let result_2 = get_shortest_path(&input, 4, 10);
+
+    (result_1, result_2)
+}
+
+base_aoc!(102, 94);
@@ -0,0 +1,105 @@
+use std::collections::{BTreeSet, HashSet};
+
+use advent_of_code_util::{
+    base_aoc, grid::Grid, grid_view::GridView, icoordinate::ICoordinate, params::Param,
+    parse::read_lines_of_chars, Coordinate,
+};
+use itertools::Itertools;
+
+const PART_1_STEPS: Param<usize> = Param::new("part_1_steps", 64);
+const PART_2_STEPS: Param<usize> = Param::new("part_2_steps", 26501365);
+
+fn num_unique_spaces_visited(
+    grid: &[Vec<char>],
+    starting_position: Coordinate,
+    num_steps: usize,
+) -> usize {
+    let mut spaces_cache: BTreeSet<(Coordinate, usize)> = BTreeSet::new();
+    let mut visit_queue = vec![(starting_position, num_steps)];
+
+    while let Some((pos, steps_left)) = visit_queue.pop() {
+        if spaces_cache.contains(&(pos, steps_left)) {
+            continue;
+        }
+        if steps_left > 0 {
+            let neighbor_spaces = pos
+                .get_surrounding_non_diagonal_coordinates(grid[0].len(), grid.len())
+                .into_iter()
+                .filter(|c| grid[c.y][c.x] != '#')
+                .map(|c| (c, steps_left - 1))
+                .collect_vec();
+            visit_queue.extend(neighbor_spaces);
+        }
+        spaces_cache.insert((pos, steps_left));
+    }
+
+    spaces_cache
+        .into_iter()
+        .filter(|(_, num_steps)| *num_steps == 0)
+        .count()
+}
+
+fn num_unique_spaces_visited_in_infinite_grid(
+    grid: &Grid<char>,
+    starting_position: Coordinate,
+    num_steps: usize,
+) -> usize {
+    // The garden is a checkerboard, so a space can be finished on if it's first reached within the
+    // steps with the same parity as the steps, and every step only ever reaches spaces one further
+    // from the start than the last. Going ring by ring then only needs the previous ring to avoid
+    // stepping backwards. That's still every space within reach, which is fine for the example's
+    // step counts but nowhere near quick enough for the real one.
+    let garden = grid.infinite();
+    let mut previous: HashSet<ICoordinate> = HashSet::new();
+    let mut current = HashSet::from([ICoordinate::try_from(starting_position).unwrap()]);
+    let mut reachable_by_parity = [1, 0];
+
+    for step in 1..=num_steps {
+        let next = current
+            .iter()
+            .flat_map(|&pos| garden.neighbours(pos))
+            .filter(|&c| garden.get(c) != Some('#') && !previous.contains(&c))
+            .collect::<HashSet<_>>();
+        reachable_by_parity[step % 2] += next.len();
+        previous = std::mem::replace(&mut current, next);
+    }
+
+    reachable_by_parity[num_steps % 2]
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let mut input = read_lines_of_chars(input_file);
+    let starting_position = input
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.iter().enumerate().find_map(|(x, space)| {
+                if *space == 'S' {
+                    Some(Coordinate { x, y })
+                } else {
+                    None
+                }
+            })
+        })
+        .next()
+        .unwrap();
+
+    input[starting_position.y][starting_position.x] = '.';
+
+    let result_1 = num_unique_spaces_visited(&input, starting_position, PART_1_STEPS.get());
+
+    let result_2 = num_unique_spaces_visited_in_infinite_grid(
+        &Grid::try_from(input).unwrap(),
+        starting_position,
+        PART_2_STEPS.get(),
+    );
+
+    (result_1, result_2)
+}
+
+base_aoc!(main(
+    "testinput",
+    params: [part_1_steps = 6, part_2_steps = 5000],
+    part_1: 16,
+    part_2: 16733044,
+));
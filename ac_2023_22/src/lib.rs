@@ -0,0 +1,138 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use advent_of_code_util::{
+    base_aoc,
+    coordinate3::{Cuboid, ICoordinate3},
+    parse::read_parsed_lines,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Brick {
+    shape: Cuboid,
+}
+impl FromStr for Brick {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start_str, end_str) = s.split_once('~').unwrap();
+        let start = start_str.parse::<ICoordinate3>().unwrap();
+        let end = end_str.parse::<ICoordinate3>().unwrap();
+
+        Ok(Self {
+            shape: Cuboid::new(start, end),
+        })
+    }
+}
+impl Brick {
+    const DOWN: ICoordinate3 = ICoordinate3 { x: 0, y: 0, z: -1 };
+
+    pub fn fall(&mut self) {
+        self.shape = self.shape.translate(Self::DOWN);
+    }
+    pub fn is_on_ground(&self) -> bool {
+        self.shape.min.z == 0
+    }
+    pub fn is_under(&self, other: &Self) -> bool {
+        self.shape.intersects(&other.shape.translate(Self::DOWN))
+    }
+}
+
+fn fall_until_stable(input: &mut [Brick]) {
+    loop {
+        let mut fell = false;
+
+        for falling_brick_index in 0..input.len() {
+            if input[falling_brick_index].is_on_ground() {
+                continue;
+            }
+
+            let mut any_support = false;
+            for colliding_brick_index in 0..input.len() {
+                if falling_brick_index != colliding_brick_index
+                    && input[colliding_brick_index].is_under(&input[falling_brick_index])
+                {
+                    any_support = true;
+                }
+            }
+            if !any_support {
+                fell = true;
+                input[falling_brick_index].fall();
+            }
+        }
+
+        if !fell {
+            break;
+        }
+    }
+}
+
+fn get_brick_graph(input: &[Brick]) -> Vec<BTreeSet<usize>> {
+    let mut graph: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); input.len()];
+
+    for brick_index in 0..input.len() {
+        if input[brick_index].is_on_ground() {
+            continue;
+        }
+        for other_brick_index in 0..input.len() {
+            if other_brick_index != brick_index
+                && input[other_brick_index].is_under(&input[brick_index])
+            {
+                graph[brick_index].insert(other_brick_index);
+            }
+        }
+    }
+
+    graph
+}
+
+fn get_num_falls_if_brick_disintegrates(graph: &[BTreeSet<usize>], index: usize) -> usize {
+    let mut falls = vec![false; graph.len()];
+    falls[index] = true;
+
+    loop {
+        let mut fell = false;
+
+        for i in 0..graph.len() {
+            // If the bricks under is empty, the brick must be resting on the ground, and therefore can't fall
+            if falls[i] || graph[i].is_empty() {
+                continue;
+            }
+            if graph[i]
+                .iter()
+                .all(|under_brick_index| falls[*under_brick_index])
+            {
+                falls[i] = true;
+                fell = true;
+            }
+        }
+
+        if !fell || !falls.contains(&false) {
+            break;
+        }
+    }
+
+    // subtract 1 because the disintegrated brick doesn't count
+    falls.into_iter().filter(|f| *f).count() - 1
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let mut input: Vec<Brick> = read_parsed_lines(input_file);
+
+    fall_until_stable(&mut input);
+
+    // - Find dependencies
+    let mut result_1 = 0;
+    let mut result_2 = 0;
+    let graph = get_brick_graph(&input);
+    for removed_brick_index in 0..input.len() {
+        let num_falls = get_num_falls_if_brick_disintegrates(&graph, removed_brick_index);
+        if num_falls == 0 {
+            result_1 += 1;
+        }
+        result_2 += num_falls;
+    }
+
+    (result_1, result_2)
+}
+
+base_aoc!(5, 7);
@@ -0,0 +1,87 @@
+use advent_of_code_util::{
+    base_aoc,
+    grid::Grid,
+    junctions::{junctions, Junctions},
+    parse::read_grid,
+    Coordinate,
+};
+use petgraph::{algo::all_simple_paths, graph::NodeIndex};
+
+// This assumes that from and to are non diagonally adjacent and are movable
+fn is_slope_accessible(from: Coordinate, to: Coordinate, input: &Grid<char>) -> bool {
+    // ...
+    // .FT
+    // ...
+    if from.x + 1 == to.x && input[to] == '<' {
+        return false;
+    }
+    // ...
+    // TF.
+    // ...
+    if from.x == to.x + 1 && input[to] == '>' {
+        return false;
+    }
+    // ...
+    // .F.
+    // .T.
+    if from.y + 1 == to.y && input[to] == '^' {
+        return false;
+    }
+    // .T.
+    // .F.
+    // ...
+    if from.y == to.y + 1 && input[to] == 'v' {
+        return false;
+    }
+    true
+}
+
+fn get_graph(input: &Grid<char>, include_slopes: bool) -> (Junctions, NodeIndex, NodeIndex) {
+    // The start is in the top row and the end in the bottom one. Some inputs have them bricked up, in
+    // which case they're next to the first open cell below and the last open cell above respectively
+    let open = |c: &char| *c != '#';
+    let bottom = input.height() - 1;
+    let start_x = (input.row(0).iter().position(open))
+        .or_else(|| input.row(1).iter().position(open))
+        .expect("Should have found the start location");
+    let end_x = (input.row(bottom).iter().rposition(open))
+        .or_else(|| input.row(bottom - 1).iter().rposition(open))
+        .expect("Should have found the end location");
+    let start = Coordinate { x: start_x, y: 0 };
+    let end = Coordinate {
+        x: end_x,
+        y: bottom,
+    };
+    let is_open = |c: Coordinate| input[c] != '#' || c == start || c == end;
+    let graph = junctions(input, [start, end], |from, to| {
+        is_open(from) && is_open(to) && (!include_slopes || is_slope_accessible(from, to, input))
+    });
+    let (start, end) = (graph.node(start).unwrap(), graph.node(end).unwrap());
+    (graph, start, end)
+}
+
+fn calculate_longest_path(graph: &Junctions, start: &NodeIndex, end: &NodeIndex) -> usize {
+    all_simple_paths::<Vec<_>, _>(&graph.graph, *start, *end, 0, None)
+        .filter_map(|p| graph.longest_walk(&p))
+        .max()
+        .unwrap()
+}
+
+fn parse(input_file: &str) -> Grid<char> {
+    read_grid(input_file)
+}
+
+fn part_1(input: &Grid<char>) -> usize {
+    let (graph, start, end) = get_graph(input, true);
+    calculate_longest_path(&graph, &start, &end)
+}
+
+fn part_2(input: &Grid<char>) -> usize {
+    let (graph, start, end) = get_graph(input, false);
+    calculate_longest_path(&graph, &start, &end)
+}
+
+base_aoc!(
+    parts(parse, part_1, part_2);
+    main("testinput", part_1: 94, part_2: 154),
+);
@@ -0,0 +1,85 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+
+use std::str::FromStr;
+
+use advent_of_code_util::{
+    base_aoc, coordinate3::ICoordinate3, params::Param, parse::read_parsed_lines,
+};
+use itertools::Itertools;
+
+const LOWER_BOUND: Param<isize> = Param::new("lower_bound", 200000000000000);
+const UPPER_BOUND: Param<isize> = Param::new("upper_bound", 400000000000000);
+
+#[derive(Debug, Clone, Copy)]
+struct Hailstone {
+    position: ICoordinate3,
+    velocity: ICoordinate3,
+}
+impl Hailstone {
+    pub fn collides_within_bound(
+        &self,
+        other: &Self,
+        lower_bound: isize,
+        upper_bound: isize,
+    ) -> bool {
+        true
+    }
+}
+impl FromStr for Hailstone {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = s.split_once('@').ok_or(())?;
+        Ok(Self {
+            position: position.trim().parse().map_err(|_| ())?,
+            velocity: velocity.trim().parse().map_err(|_| ())?,
+        })
+    }
+}
+
+fn num_collisions(hailstones: &[Hailstone], lower_bound: isize, upper_bound: isize) -> usize {
+    let mut num_collisions = 0;
+
+    for i in 0..hailstones.len() {
+        for j in (i + 1)..hailstones.len() {
+            if hailstones[i].collides_within_bound(&hailstones[j], lower_bound, upper_bound) {
+                num_collisions += 1;
+            }
+        }
+    }
+
+    num_collisions
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input: Vec<Hailstone> = read_parsed_lines(input_file);
+
+    let lower_bound = LOWER_BOUND.get();
+    let upper_bound = UPPER_BOUND.get();
+
+    let xy_hailstones = input
+        .clone()
+        .into_iter()
+        .map(|h| {
+            let mut new_h = h;
+            new_h.position.z = 0;
+            new_h.velocity.z = 0;
+            new_h
+        })
+        .collect_vec();
+
+    let result_1 = num_collisions(&xy_hailstones, upper_bound, lower_bound);
+
+    (result_1, 0)
+}
+
+base_aoc!(
+    #[ignore = "unfinished: part 1 counts 10 crossings in the example instead of 2, and part 2 isn't solved"]
+    main(
+        "testinput",
+        params: [lower_bound = 7, upper_bound = 27],
+        part_1: 2,
+        part_2: 0,
+    ),
+);
@@ -0,0 +1,51 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use advent_of_code_util::{base_aoc, parse::read_lines, region::connected_components};
+use itertools::Itertools;
+
+fn parse_input(input: Vec<String>) -> (Vec<String>, BTreeMap<String, BTreeSet<String>>) {
+    let mut nodes = vec![];
+    let mut edges = BTreeMap::new();
+
+    for line in input {
+        let (node, raw_edges) = line
+            .split(": ")
+            .map(|s| s.to_string())
+            .collect_tuple()
+            .unwrap();
+        let node_edges: BTreeSet<String> = raw_edges
+            .split_whitespace()
+            .map(|s| s.to_string())
+            .collect();
+        nodes.push(node.clone());
+        edges
+            .entry(node.clone())
+            .or_insert(BTreeSet::new())
+            .append(&mut node_edges.clone());
+        for edge in node_edges {
+            edges
+                .entry(edge.clone())
+                .or_insert(BTreeSet::new())
+                .insert(node.clone());
+        }
+    }
+
+    (nodes, edges)
+}
+
+fn group_sizes(nodes: &[String], edges: &BTreeMap<String, BTreeSet<String>>) -> Vec<usize> {
+    connected_components(nodes.iter().cloned(), |node| edges[node].iter().cloned())
+        .iter()
+        .map(BTreeSet::len)
+        .collect()
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let (nodes, edges) = parse_input(read_lines(input_file));
+
+    let result_1 = group_sizes(&nodes, &edges).iter().product::<usize>();
+
+    (result_1, 0)
+}
+
+base_aoc!(54, 0);
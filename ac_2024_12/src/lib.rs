@@ -0,0 +1,17 @@
+use advent_of_code_util::{base_aoc, parse::read_grid, region::regions};
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_grid(input_file);
+    let areas = regions(&input, |a, b| a == b);
+
+    let answer_1 = areas
+        .iter()
+        .map(|area| area.area() * area.perimeter())
+        .sum();
+
+    let answer_2 = areas.iter().map(|area| area.area() * area.sides()).sum();
+
+    (answer_1, answer_2)
+}
+
+base_aoc!(1930, 1206);
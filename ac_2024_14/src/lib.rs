@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use advent_of_code_util::{
+    base_aoc, icoordinate::ICoordinate, params::Param, parse::read_parsed_lines,
+    sparse_grid::SparseGrid,
+};
+use itertools::Itertools;
+
+const WIDTH: Param<isize> = Param::new("width", 101);
+const HEIGHT: Param<isize> = Param::new("height", 103);
+
+fn quadrant(coord: ICoordinate, width: isize, height: isize) -> Option<usize> {
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+    match (coord.x.cmp(&mid_x), coord.y.cmp(&mid_y)) {
+        (std::cmp::Ordering::Less, std::cmp::Ordering::Less) => Some(0),
+        (std::cmp::Ordering::Greater, std::cmp::Ordering::Less) => Some(1),
+        (std::cmp::Ordering::Greater, std::cmp::Ordering::Greater) => Some(2),
+        (std::cmp::Ordering::Less, std::cmp::Ordering::Greater) => Some(3),
+        (std::cmp::Ordering::Equal, _) => None,
+        (_, std::cmp::Ordering::Equal) => None,
+    }
+}
+
+#[derive(Debug)]
+struct Robot {
+    pos: ICoordinate,
+    vel: ICoordinate,
+}
+impl Robot {
+    pub fn position_after_n_turns(&self, n: isize, width: isize, height: isize) -> ICoordinate {
+        (self.pos + self.vel * n).wrap(width, height)
+    }
+}
+impl FromStr for Robot {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pos, vel) = s
+            .split_ascii_whitespace()
+            .map(|s| s[2..].parse::<ICoordinate>().unwrap())
+            .collect_tuple()
+            .unwrap();
+        Ok(Self { pos, vel })
+    }
+}
+
+fn get_program_output(input_file: &str) -> (isize, isize) {
+    let input: Vec<Robot> = read_parsed_lines(input_file);
+    let (width, height) = (WIDTH.get(), HEIGHT.get());
+
+    let answer_1 = {
+        let mut robots_per_quadrant = [0, 0, 0, 0];
+        input
+            .iter()
+            .flat_map(|r| quadrant(r.position_after_n_turns(100, width, height), width, height))
+            .for_each(|quad| robots_per_quadrant[quad] += 1);
+        robots_per_quadrant.into_iter().product()
+    };
+
+    let answer_2 = (1..10000)
+        .find(|&i| {
+            let positions = input
+                .iter()
+                .map(|r| r.position_after_n_turns(i, width, height))
+                .collect::<SparseGrid<ICoordinate>>();
+            positions.points().any(|p| {
+                p.neighbours_with_diagonals()
+                    .all(|surrounding| positions.contains(surrounding))
+            })
+        })
+        .unwrap_or(0);
+
+    (answer_1, answer_2)
+}
+
+base_aoc!(main("testinput", params: [width = 11, height = 7], part_1: 12, part_2: 0));
@@ -0,0 +1,72 @@
+use std::collections::BTreeSet;
+
+use advent_of_code_util::{
+    base_aoc, grid::Grid, parse::read_grid, search::dijkstra_all, Coordinate, Direction,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+struct Node {
+    position: Coordinate,
+    facing: Direction,
+}
+impl Node {
+    pub fn surrounding_nodes_with_costs(&self, grid: &Grid<char>) -> Vec<(Self, usize)> {
+        let mut nodes = vec![
+            (
+                Self {
+                    facing: self.facing.turn_right(),
+                    ..*self
+                },
+                1000,
+            ),
+            (
+                Self {
+                    facing: self.facing.turn_left(),
+                    ..*self
+                },
+                1000,
+            ),
+        ];
+        if let Some(ahead) = self.position.step(self.facing)
+            && grid.get(ahead).is_some_and(|&c| c != '#')
+        {
+            nodes.push((
+                Self {
+                    position: ahead,
+                    ..*self
+                },
+                1,
+            ));
+        }
+        nodes
+    }
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_grid(input_file);
+
+    let start = Node {
+        position: input.position_of(&'S').unwrap(),
+        facing: Direction::Right,
+    };
+    let end_coordinate = input.position_of(&'E').unwrap();
+
+    let best_paths = dijkstra_all(
+        [start],
+        |node| node.surrounding_nodes_with_costs(&input),
+        |node| node.position == end_coordinate,
+    );
+
+    let answer_1 = best_paths.goal_cost().unwrap();
+
+    let answer_2 = best_paths
+        .states_on_paths()
+        .into_iter()
+        .map(|node| node.position)
+        .collect::<BTreeSet<_>>()
+        .len();
+
+    (answer_1, answer_2)
+}
+
+base_aoc!(11048, 64);
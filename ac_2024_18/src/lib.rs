@@ -0,0 +1,57 @@
+use advent_of_code_util::{
+    base_aoc, grid::Grid, params::Param, parse::read_parsed_lines, search::bfs_grid, Coordinate,
+};
+
+const SIZE: Param<usize> = Param::new("size", 71);
+const PART_1_BYTES: Param<usize> = Param::new("part_1_bytes", 1024);
+
+fn find_shortest_path(grid: &Grid<bool>) -> Option<Vec<Coordinate>> {
+    let target = Coordinate {
+        x: grid.width() - 1,
+        y: grid.height() - 1,
+    };
+    bfs_grid(
+        grid,
+        [Coordinate { x: 0, y: 0 }],
+        |&corrupted| !corrupted,
+        |&c| c == target,
+    )
+    .path()
+}
+
+fn get_program_output(input_file: &str) -> (usize, String) {
+    let input: Vec<Coordinate> = read_parsed_lines(input_file);
+    let size = SIZE.get();
+
+    let answer_1 = {
+        let mut grid = Grid::new(size, size, false);
+        for byte in &input[..PART_1_BYTES.get()] {
+            grid[*byte] = true;
+        }
+
+        find_shortest_path(&grid).unwrap().len() - 1
+    };
+    let answer_2 = 'block: {
+        let mut grid = Grid::new(size, size, false);
+        let mut current_shortest_path = find_shortest_path(&grid).unwrap();
+        for byte in &input {
+            grid[*byte] = true;
+            if current_shortest_path.contains(byte) {
+                match find_shortest_path(&grid) {
+                    Some(path) => current_shortest_path = path,
+                    None => break 'block format!("{},{}", byte.x, byte.y),
+                }
+            }
+        }
+        unreachable!()
+    };
+
+    (answer_1, answer_2)
+}
+
+base_aoc!(main(
+    "testinput",
+    params: [size = 7, part_1_bytes = 12],
+    part_1: 22,
+    part_2: "6,1",
+));
@@ -0,0 +1,59 @@
+#![allow(unused_variables, unreachable_code, dead_code)]
+use std::collections::{BTreeMap, BTreeSet};
+
+use advent_of_code_util::{
+    base_aoc, params::Param, parse::read_grid, search::bfs_grid, Coordinate,
+};
+
+const AMOUNT_SAVED_THRESHOLD: Param<usize> = Param::new("amount_saved_threshold", 100);
+
+fn get_nearby_path_coords(
+    c: Coordinate,
+    all_path_coords: &BTreeSet<Coordinate>,
+    spaces_away: usize,
+) -> Vec<Coordinate> {
+    unimplemented!()
+}
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_grid(input_file);
+    let amount_saved_threshold = AMOUNT_SAVED_THRESHOLD.get();
+
+    let all_path_coords: BTreeSet<Coordinate> = input
+        .enumerate()
+        .filter(|(_, c)| **c != '#')
+        .map(|(coord, _)| coord)
+        .collect();
+    let start_coord = input.position_of(&'S').unwrap();
+    let end_coord = input.position_of(&'E').unwrap();
+
+    // Get vec of every point along the path
+    let path_vec: Vec<Coordinate> =
+        bfs_grid(&input, [start_coord], |&c| c != '#', |&c| c == end_coord)
+            .path()
+            .unwrap();
+    // Create map of every point -> time left
+    let space_left_for_point: BTreeMap<Coordinate, usize> =
+        bfs_grid(&input, [end_coord], |&c| c != '#', |_| false)
+            .distances()
+            .enumerate()
+            .filter_map(|(coord, reached)| Some((coord, reached.as_ref()?.cost)))
+            .collect();
+
+    for c in path_vec {
+        // Create function that, for a given point along the path, finds all other points within N spaces
+        // Loop through every point along the path. For each, get all points within cheat range. Subtract space left from current from space left at the end. Add the manhatten distance of the cheat to get total time saved.
+        todo!()
+    }
+
+    // Filter time saved > amount_saved_threshold
+
+    (0, 0)
+}
+
+base_aoc!(main(
+    "testinput",
+    params: [amount_saved_threshold = 20],
+    part_1: 5,
+    part_2: 0,
+));
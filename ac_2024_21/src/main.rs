@@ -1,9 +1,3 @@
-use advent_of_code_util::{base_aoc, parse::read_lines};
-
-fn get_program_output(input_file: &str) -> (usize, usize) {
-    let input = read_lines(input_file);
-
-    (0, 0)
+fn main() {
+    advent_of_code_util::solution::main(&ac_2024_21::DAY);
 }
-
-base_aoc!(126384, 0);
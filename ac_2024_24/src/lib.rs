@@ -0,0 +1,10 @@
+#![allow(unused_variables)]
+use advent_of_code_util::{base_aoc, parse::read_lines};
+
+fn get_program_output(input_file: &str) -> (usize, usize) {
+    let input = read_lines(input_file);
+
+    (0, 0)
+}
+
+base_aoc!(0, 0);
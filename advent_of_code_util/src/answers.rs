@@ -0,0 +1,220 @@
+use std::{fmt::Display, fs, path::Path, str::FromStr};
+
+use crate::solution::{has_input, Day, Outputs, Part};
+
+const PART_1_PREFIX: &str = "Part 1 output: ";
+const PART_2_PREFIX: &str = "Part 2 output: ";
+
+/**
+Accepted answers for a day's real input, stored in an `answers` file next to `input`. The format is
+the same as the per-day binaries print, so either part can be left out and multi-line answers just
+carry on until the next part:
+
+```text
+Part 1 output: 13140
+Part 2 output: ##..##..
+##..##..
+```
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut current: Option<&mut Option<String>> = None;
+        for line in s.lines() {
+            if let Some(answer) = line.strip_prefix(PART_1_PREFIX) {
+                answers.part_1 = Some(answer.to_string());
+                current = Some(&mut answers.part_1);
+            } else if let Some(answer) = line.strip_prefix(PART_2_PREFIX) {
+                answers.part_2 = Some(answer.to_string());
+                current = Some(&mut answers.part_2);
+            } else if let Some(Some(answer)) = current.as_mut() {
+                answer.push('\n');
+                answer.push_str(line);
+            } else if !line.trim().is_empty() {
+                return Err(format!("Expected \"{}\", got \"{}\"", PART_1_PREFIX, line));
+            }
+        }
+        Ok(answers)
+    }
+}
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(part_1) = &self.part_1 {
+            writeln!(f, "{}{}", PART_1_PREFIX, part_1)?;
+        }
+        if let Some(part_2) = &self.part_2 {
+            writeln!(f, "{}{}", PART_2_PREFIX, part_2)?;
+        }
+        Ok(())
+    }
+}
+impl Answers {
+    /**
+    Reads an answers file, or `None` if there isn't one
+    */
+    pub fn read<P: AsRef<Path>>(path: P) -> Option<Result<Self, String>> {
+        fs::read_to_string(path)
+            .ok()
+            .map(|contents| contents.parse())
+    }
+
+    /**
+    The parts with a known answer, or `None` for both. Days that can run the parts separately
+    don't need to solve a part without an answer.
+    */
+    pub fn parts(&self) -> Option<Part> {
+        match (&self.part_1, &self.part_2) {
+            (Some(_), None) => Some(Part::One),
+            (None, Some(_)) => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    /**
+    Describes every part whose known answer doesn't match `outputs`
+    */
+    pub fn mismatches(&self, (part_1_output, part_2_output): &Outputs) -> Vec<String> {
+        [
+            (1, &self.part_1, part_1_output),
+            (2, &self.part_2, part_2_output),
+        ]
+        .into_iter()
+        .filter_map(|(part, expected, actual)| match expected {
+            Some(expected) => match actual {
+                Some(actual) if expected.trim_end() == actual.trim_end() => None,
+                Some(actual) => Some(format!(
+                    "Part {} expected {}, got {}",
+                    part, expected, actual
+                )),
+                None => Some(format!(
+                    "Part {} expected {}, but wasn't solved",
+                    part, expected
+                )),
+            },
+            None => None,
+        })
+        .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verification {
+    MissingInput,
+    MissingAnswers,
+    Verified,
+    Mismatched(Vec<String>),
+}
+
+/**
+Solves the real input and compares it with the day's answers file, without solving anything if
+either file is missing
+*/
+pub fn verify(day: &Day) -> Verification {
+    if !has_input(day.input_path()) {
+        return Verification::MissingInput;
+    }
+    let answers = match Answers::read(day.answers_path()) {
+        None => return Verification::MissingAnswers,
+        Some(Ok(answers)) => answers,
+        Some(Err(err)) => return Verification::Mismatched(vec![err]),
+    };
+    let outputs = day.solve_part(&day.input_path(), answers.parts());
+    let mismatches = answers.mismatches(&outputs);
+    if mismatches.is_empty() {
+        Verification::Verified
+    } else {
+        Verification::Mismatched(mismatches)
+    }
+}
+
+/**
+Body of the `real_input` test generated by `base_aoc!`
+*/
+pub fn assert_verified(day: &Day) {
+    match verify(day) {
+        Verification::MissingInput => println!("Skipping {}: no input", day.name),
+        Verification::MissingAnswers => println!("Skipping {}: no answers", day.name),
+        Verification::Verified => {}
+        Verification::Mismatched(mismatches) => panic!("{}", mismatches.join("\n")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays() {
+        let answers = "Part 1 output: 13140\nPart 2 output: ##..\n#..#\n"
+            .parse::<Answers>()
+            .unwrap();
+        assert_eq!(answers.part_1.as_deref(), Some("13140"));
+        assert_eq!(answers.part_2.as_deref(), Some("##..\n#..#"));
+        assert_eq!(
+            answers.to_string().parse::<Answers>().unwrap(),
+            answers.clone()
+        );
+
+        let only_part_1 = "Part 1 output: 5\n".parse::<Answers>().unwrap();
+        assert_eq!(only_part_1.part_2, None);
+        assert!("5\n".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn mismatches() {
+        let answers = Answers {
+            part_1: Some("1".to_string()),
+            part_2: None,
+        };
+        assert_eq!(answers.parts(), Some(Part::One));
+        assert!(answers
+            .mismatches(&(Some("1".to_string()), Some("anything".to_string())))
+            .is_empty());
+        assert_eq!(
+            answers.mismatches(&(Some("2".to_string()), None)),
+            vec!["Part 1 expected 1, got 2".to_string()]
+        );
+        assert_eq!(
+            answers.mismatches(&(None, None)),
+            vec!["Part 1 expected 1, but wasn't solved".to_string()]
+        );
+    }
+
+    fn get_program_output(input_file: &str) -> (usize, usize) {
+        let input = fs::read_to_string(input_file).unwrap();
+        (input.len(), input.lines().count())
+    }
+
+    #[test]
+    fn verify_skips_missing_files() {
+        let dir = std::env::temp_dir().join(format!("aoc_answers_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let day = Day {
+            name: "ac_2024_09",
+            manifest_dir: Box::leak(dir.to_str().unwrap().to_string().into_boxed_str()),
+            solution: &(get_program_output as fn(&str) -> (_, _)),
+        };
+
+        assert_eq!(verify(&day), Verification::MissingInput);
+        fs::write(day.input_path(), "").unwrap();
+        assert_eq!(verify(&day), Verification::MissingInput);
+        fs::write(day.input_path(), "ab\ncd\n").unwrap();
+        assert_eq!(verify(&day), Verification::MissingAnswers);
+        fs::write(day.answers_path(), "Part 1 output: 6\nPart 2 output: 2\n").unwrap();
+        assert_eq!(verify(&day), Verification::Verified);
+        fs::write(day.answers_path(), "Part 2 output: 3\n").unwrap();
+        let verification = verify(&day);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            verification,
+            Verification::Mismatched(vec!["Part 2 expected 3, got 2".to_string()])
+        );
+    }
+}
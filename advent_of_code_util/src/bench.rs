@@ -0,0 +1,350 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use itertools::Itertools;
+
+/**
+Regressions are only reported when the median total gets this much slower than the previous run
+*/
+pub const REGRESSION_THRESHOLD: f64 = 1.2;
+
+/**
+How long a single run took. Phases that a solution doesn't expose separately (tuple-style days do
+parsing and both parts in one call) are `None`, and only show up in `total`.
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+    pub total: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+impl Stats {
+    pub fn from_durations(durations: &[Duration]) -> Option<Self> {
+        let sorted = durations.iter().copied().sorted().collect_vec();
+        let len = sorted.len();
+        if len == 0 {
+            return None;
+        }
+        let median = if len % 2 == 1 {
+            sorted[len / 2]
+        } else {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        };
+        Some(Stats {
+            min: sorted[0],
+            median,
+            max: sorted[len - 1],
+        })
+    }
+}
+
+/**
+Min/median/max of each phase the day reports over repeated runs of it
+*/
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Summary {
+    pub name: String,
+    pub runs: usize,
+    pub parse: Option<Stats>,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+    pub total: Stats,
+}
+impl Summary {
+    pub fn from_runs(name: &str, runs: &[Timings]) -> Self {
+        let phase = |get: fn(&Timings) -> Option<Duration>| {
+            runs.iter()
+                .map(get)
+                .collect::<Option<Vec<_>>>()
+                .and_then(|durations| Stats::from_durations(&durations))
+        };
+        Summary {
+            name: name.to_string(),
+            runs: runs.len(),
+            parse: phase(|timings| timings.parse),
+            part_1: phase(|timings| timings.part_1),
+            part_2: phase(|timings| timings.part_2),
+            total: phase(|timings| Some(timings.total)).expect("summary needs at least one run"),
+        }
+    }
+
+    pub fn describe(&self) -> Vec<String> {
+        let describe_stats = |label: &str, stats: &Stats| {
+            format!(
+                "{}: {:.2?} (min {:.2?}, median {:.2?}, max {:.2?} over {} runs)",
+                label, stats.median, stats.min, stats.median, stats.max, self.runs
+            )
+        };
+        [
+            ("Parse", self.parse),
+            ("Part 1", self.part_1),
+            ("Part 2", self.part_2),
+            ("Total", Some(self.total)),
+        ]
+        .into_iter()
+        .filter_map(|(label, stats)| stats.map(|stats| describe_stats(label, &stats)))
+        .collect_vec()
+    }
+}
+
+fn format_median(stats: Option<Stats>) -> String {
+    stats
+        .map(|stats| format!("{:.2?}", stats.median))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/**
+Formats a human readable table of summaries, comparing median totals against `previous` where a day
+appears in both
+*/
+pub fn format_table(summaries: &[Summary], previous: &BTreeMap<String, Duration>) -> String {
+    let header = [
+        "day",
+        "parse",
+        "part 1",
+        "part 2",
+        "min total",
+        "median total",
+        "max total",
+        "previous",
+    ]
+    .map(str::to_string);
+    let rows = summaries
+        .iter()
+        .map(|summary| {
+            let comparison = match previous.get(&summary.name) {
+                // Too quick to have measured, so there's nothing to compare against
+                Some(previous_median) if previous_median.is_zero() => {
+                    format!("{:.2?}", previous_median)
+                }
+                Some(previous_median) => {
+                    let ratio = summary.total.median.as_secs_f64() / previous_median.as_secs_f64();
+                    let flag = if ratio > REGRESSION_THRESHOLD {
+                        " REGRESSION"
+                    } else {
+                        ""
+                    };
+                    format!(
+                        "{:.2?} ({:+.1}%){}",
+                        previous_median,
+                        (ratio - 1.0) * 100.0,
+                        flag
+                    )
+                }
+                None => "-".to_string(),
+            };
+            [
+                summary.name.clone(),
+                format_median(summary.parse),
+                format_median(summary.part_1),
+                format_median(summary.part_2),
+                format!("{:.2?}", summary.total.min),
+                format!("{:.2?}", summary.total.median),
+                format!("{:.2?}", summary.total.max),
+                comparison,
+            ]
+        })
+        .collect_vec();
+    let widths = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(rows.iter())
+                .map(|row| row[column].len())
+                .max()
+                .unwrap()
+        })
+        .collect_vec();
+    std::iter::once(&header)
+        .chain(rows.iter())
+        .map(|row| {
+            row.iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .join("\n")
+}
+
+const SUMMARY_HEADER: &str = "day\truns\tparse_ns\tpart_1_ns\tpart_2_ns\tmin_ns\tmedian_ns\tmax_ns";
+
+/**
+Writes summaries as tab separated nanoseconds, so they can be read back by `read_previous_medians`.
+Rows already in the file for days that weren't rerun are kept.
+*/
+pub fn write_summaries<P: AsRef<Path>>(path: P, summaries: &[Summary]) -> io::Result<()> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let nanos = |stats: Option<Stats>| {
+        stats
+            .map(|stats| stats.median.as_nanos().to_string())
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let mut rows = fs::read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .skip(1)
+        .filter_map(|line| Some((line.split('\t').next()?.to_string(), line.to_string())))
+        .collect::<BTreeMap<_, _>>();
+    for summary in summaries {
+        let row = format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            summary.name,
+            summary.runs,
+            nanos(summary.parse),
+            nanos(summary.part_1),
+            nanos(summary.part_2),
+            summary.total.min.as_nanos(),
+            summary.total.median.as_nanos(),
+            summary.total.max.as_nanos(),
+        );
+        rows.insert(summary.name.clone(), row);
+    }
+    let mut file = fs::File::create(path)?;
+    writeln!(file, "{}", SUMMARY_HEADER)?;
+    for row in rows.values() {
+        writeln!(file, "{}", row)?;
+    }
+    Ok(())
+}
+
+/**
+Median totals per day from a file written by `write_summaries`. A missing file is an empty map.
+*/
+pub fn read_previous_medians<P: AsRef<Path>>(path: P) -> BTreeMap<String, Duration> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return BTreeMap::new();
+    };
+    contents
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split('\t').collect_vec();
+            let median = fields.get(6)?.parse::<u64>().ok()?;
+            Some((fields[0].to_string(), Duration::from_nanos(median)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn total_only(millis: u64) -> Timings {
+        Timings {
+            total: ms(millis),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn stats_from_durations() {
+        assert_eq!(Stats::from_durations(&[]), None);
+        assert_eq!(
+            Stats::from_durations(&[ms(5), ms(1), ms(3)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            })
+        );
+        assert_eq!(
+            Stats::from_durations(&[ms(4), ms(1), ms(2), ms(8)])
+                .unwrap()
+                .median,
+            ms(3)
+        );
+    }
+
+    #[test]
+    fn summary_only_includes_measured_phases() {
+        let summary = Summary::from_runs("ac_2024_17", &[total_only(3), total_only(1)]);
+        assert_eq!(summary.parse, None);
+        assert_eq!(summary.total.median, ms(2));
+        assert_eq!(summary.describe().len(), 1);
+
+        let split = Timings {
+            parse: Some(ms(1)),
+            part_1: Some(ms(2)),
+            part_2: Some(ms(3)),
+            total: ms(6),
+        };
+        let summary = Summary::from_runs("ac_2024_17", &[split]);
+        assert_eq!(summary.part_2.unwrap().max, ms(3));
+        assert_eq!(summary.describe().len(), 4);
+    }
+
+    #[test]
+    fn round_trips_and_flags_regressions() {
+        let path = std::env::temp_dir().join(format!("aoc_bench_{}.tsv", std::process::id()));
+        let summaries = vec![
+            Summary::from_runs("ac_2023_23", &[total_only(10)]),
+            Summary::from_runs("ac_2024_17", &[total_only(20)]),
+        ];
+        write_summaries(&path, &summaries).unwrap();
+        let previous = read_previous_medians(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(previous.get("ac_2023_23"), Some(&ms(10)));
+        assert_eq!(previous.get("ac_2024_17"), Some(&ms(20)));
+
+        let slower = vec![
+            Summary::from_runs("ac_2023_23", &[total_only(11)]),
+            Summary::from_runs("ac_2024_17", &[total_only(40)]),
+            Summary::from_runs("ac_2024_18", &[total_only(1)]),
+        ];
+        let table = format_table(&slower, &previous);
+        let lines = table.lines().collect_vec();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("ac_2023_23") && !lines[1].contains("REGRESSION"));
+        assert!(lines[2].contains("+100.0%") && lines[2].ends_with("REGRESSION"));
+        assert!(lines[3].ends_with('-'));
+        assert!(read_previous_medians(path).is_empty());
+
+        let unmeasured = BTreeMap::from([("ac_2023_23".to_string(), Duration::ZERO)]);
+        let table = format_table(&slower[..1], &unmeasured);
+        assert!(table.lines().nth(1).unwrap().ends_with("0.00ns"));
+        assert!(!table.contains("inf") && !table.contains("REGRESSION"));
+    }
+
+    #[test]
+    fn rewriting_keeps_other_days() {
+        let path = std::env::temp_dir().join(format!("aoc_bench_keep_{}.tsv", std::process::id()));
+        write_summaries(
+            &path,
+            &[
+                Summary::from_runs("ac_2023_22", &[total_only(5)]),
+                Summary::from_runs("ac_2023_23", &[total_only(10)]),
+            ],
+        )
+        .unwrap();
+        write_summaries(&path, &[Summary::from_runs("ac_2023_23", &[total_only(7)])]).unwrap();
+        let previous = read_previous_medians(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            previous.into_iter().collect_vec(),
+            vec![
+                ("ac_2023_22".to_string(), ms(5)),
+                ("ac_2023_23".to_string(), ms(7))
+            ]
+        );
+    }
+}
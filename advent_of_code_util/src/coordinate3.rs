@@ -0,0 +1,402 @@
+use std::{
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::parse_components;
+
+/**
+Offsets to the six neighbours sharing a face
+*/
+const FACE_OFFSETS: [(isize, isize, isize); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/**
+Offsets to all 26 neighbours, including the ones sharing only an edge or a corner
+*/
+fn all_offsets() -> impl Iterator<Item = (isize, isize, isize)> {
+    (-1..=1)
+        .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| (x, y, z))))
+        .filter(|&offset| offset != (0, 0, 0))
+}
+
+/**
+A point in 3D space that can't go negative. It has the same operations as `ICoordinate3` except
+`Neg`, which would always leave it.
+*/
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct Coordinate3 {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+impl FromStr for Coordinate3 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_components(s, "x,y,z")?;
+        Ok(Coordinate3 { x, y, z })
+    }
+}
+impl Coordinate3 {
+    pub const ORIGIN: Coordinate3 = Coordinate3 { x: 0, y: 0, z: 0 };
+
+    fn offset(&self, (dx, dy, dz): (isize, isize, isize)) -> Option<Self> {
+        Some(Coordinate3 {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+            z: self.z.checked_add_signed(dz)?,
+        })
+    }
+
+    /**
+    The up to six neighbours sharing a face, leaving out any with a negative component
+    */
+    pub fn neighbours(self) -> impl Iterator<Item = Coordinate3> {
+        FACE_OFFSETS
+            .into_iter()
+            .filter_map(move |offset| self.offset(offset))
+    }
+
+    /**
+    The up to 26 neighbours including diagonals, leaving out any with a negative component
+    */
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Coordinate3> {
+        all_offsets().filter_map(move |offset| self.offset(offset))
+    }
+
+    pub fn manhattan_distance(&self, other: &Coordinate3) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /**
+    The square of the straight line distance, which orders pairs the same way without needing
+    floats
+    */
+    pub fn squared_distance(&self, other: &Coordinate3) -> usize {
+        self.x.abs_diff(other.x).pow(2)
+            + self.y.abs_diff(other.y).pow(2)
+            + self.z.abs_diff(other.z).pow(2)
+    }
+}
+impl Add for Coordinate3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Coordinate3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+impl Sub for Coordinate3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coordinate3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+impl Mul<usize> for Coordinate3 {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        Coordinate3 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+impl From<(usize, usize, usize)> for Coordinate3 {
+    fn from((x, y, z): (usize, usize, usize)) -> Self {
+        Coordinate3 { x, y, z }
+    }
+}
+impl TryFrom<ICoordinate3> for Coordinate3 {
+    type Error = String;
+
+    fn try_from(coordinate: ICoordinate3) -> Result<Self, Self::Error> {
+        match (
+            usize::try_from(coordinate.x),
+            usize::try_from(coordinate.y),
+            usize::try_from(coordinate.z),
+        ) {
+            (Ok(x), Ok(y), Ok(z)) => Ok(Coordinate3 { x, y, z }),
+            _ => Err(format!("{:?} is negative", coordinate)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct ICoordinate3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+impl FromStr for ICoordinate3 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_components(s, "x,y,z")?;
+        Ok(ICoordinate3 { x, y, z })
+    }
+}
+impl ICoordinate3 {
+    pub const ORIGIN: ICoordinate3 = ICoordinate3 { x: 0, y: 0, z: 0 };
+
+    pub fn neighbours(self) -> impl Iterator<Item = ICoordinate3> {
+        FACE_OFFSETS
+            .into_iter()
+            .map(move |offset| self + ICoordinate3::from(offset))
+    }
+
+    /**
+    All 26 neighbours, including the ones sharing only an edge or a corner
+    */
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = ICoordinate3> {
+        all_offsets().map(move |offset| self + ICoordinate3::from(offset))
+    }
+
+    pub fn manhattan_distance(&self, other: &ICoordinate3) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /**
+    The square of the straight line distance, which orders pairs the same way without needing
+    floats
+    */
+    pub fn squared_distance(&self, other: &ICoordinate3) -> usize {
+        self.x.abs_diff(other.x).pow(2)
+            + self.y.abs_diff(other.y).pow(2)
+            + self.z.abs_diff(other.z).pow(2)
+    }
+}
+impl From<(isize, isize, isize)> for ICoordinate3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        ICoordinate3 { x, y, z }
+    }
+}
+impl TryFrom<Coordinate3> for ICoordinate3 {
+    type Error = String;
+
+    fn try_from(coordinate: Coordinate3) -> Result<Self, Self::Error> {
+        match (
+            isize::try_from(coordinate.x),
+            isize::try_from(coordinate.y),
+            isize::try_from(coordinate.z),
+        ) {
+            (Ok(x), Ok(y), Ok(z)) => Ok(ICoordinate3 { x, y, z }),
+            _ => Err(format!("{:?} is too big for an ICoordinate3", coordinate)),
+        }
+    }
+}
+impl Add for ICoordinate3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ICoordinate3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+impl Sub for ICoordinate3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        ICoordinate3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+impl Mul<isize> for ICoordinate3 {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        ICoordinate3 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+impl Neg for ICoordinate3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        ICoordinate3 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+/**
+An axis-aligned box, including both corners
+*/
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct Cuboid {
+    pub min: ICoordinate3,
+    pub max: ICoordinate3,
+}
+impl Cuboid {
+    /**
+    The box spanned by two opposite corners, in either order
+    */
+    pub fn new(a: ICoordinate3, b: ICoordinate3) -> Self {
+        Cuboid {
+            min: ICoordinate3 {
+                x: a.x.min(b.x),
+                y: a.y.min(b.y),
+                z: a.z.min(b.z),
+            },
+            max: ICoordinate3 {
+                x: a.x.max(b.x),
+                y: a.y.max(b.y),
+                z: a.z.max(b.z),
+            },
+        }
+    }
+
+    pub fn contains(&self, point: &ICoordinate3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    pub fn intersects(&self, other: &Cuboid) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /**
+    The box both cover, if they overlap at all
+    */
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = ICoordinate3 {
+            x: self.min.x.max(other.min.x),
+            y: self.min.y.max(other.min.y),
+            z: self.min.z.max(other.min.z),
+        };
+        let max = ICoordinate3 {
+            x: self.max.x.min(other.max.x),
+            y: self.max.y.min(other.max.y),
+            z: self.max.z.min(other.max.z),
+        };
+        if min.x <= max.x && min.y <= max.y && min.z <= max.z {
+            Some(Cuboid { min, max })
+        } else {
+            None
+        }
+    }
+
+    /**
+    The number of integer points inside
+    */
+    pub fn volume(&self) -> usize {
+        (self.max.x.abs_diff(self.min.x) + 1)
+            * (self.max.y.abs_diff(self.min.y) + 1)
+            * (self.max.z.abs_diff(self.min.z) + 1)
+    }
+
+    pub fn translate(&self, offset: ICoordinate3) -> Cuboid {
+        Cuboid {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses() {
+        assert_eq!(
+            "1,0,1".parse::<Coordinate3>(),
+            Ok(Coordinate3 { x: 1, y: 0, z: 1 })
+        );
+        assert_eq!(
+            "19, 13, -30".parse::<ICoordinate3>(),
+            Ok(ICoordinate3 {
+                x: 19,
+                y: 13,
+                z: -30
+            })
+        );
+        assert_eq!(
+            "1,2".parse::<ICoordinate3>(),
+            Err("Expected x,y,z, got \"1,2\"".to_string())
+        );
+        assert!("1,2,-3".parse::<Coordinate3>().is_err());
+    }
+
+    #[test]
+    fn neighbours_and_distances() {
+        let origin = Coordinate3::ORIGIN;
+        assert_eq!(origin.neighbours().count(), 3);
+        assert_eq!(origin.neighbours_with_diagonals().count(), 7);
+        let point = ICoordinate3 { x: 1, y: -2, z: 3 };
+        assert_eq!(point.neighbours().count(), 6);
+        assert!(point
+            .neighbours()
+            .all(|neighbour| neighbour.manhattan_distance(&point) == 1));
+        assert_eq!(point.neighbours_with_diagonals().count(), 26);
+        assert_eq!(point.manhattan_distance(&ICoordinate3::ORIGIN), 6);
+        assert_eq!(point.squared_distance(&ICoordinate3::ORIGIN), 14);
+        assert_eq!(
+            Coordinate3 {
+                x: 162,
+                y: 817,
+                z: 812
+            }
+            .squared_distance(&Coordinate3 {
+                x: 425,
+                y: 690,
+                z: 689
+            }),
+            263 * 263 + 127 * 127 + 123 * 123
+        );
+        assert_eq!(point * 2 - point, point);
+        let corner = Coordinate3::from((1, 2, 3));
+        assert_eq!(corner * 3 - corner, corner + corner);
+        assert_eq!(point + -point, ICoordinate3::ORIGIN);
+        assert!(Coordinate3::try_from(point).is_err());
+        assert_eq!(
+            ICoordinate3::try_from(Coordinate3 { x: 1, y: 2, z: 3 }),
+            Ok(ICoordinate3 { x: 1, y: 2, z: 3 })
+        );
+    }
+
+    #[test]
+    fn cuboids() {
+        let a = Cuboid::new((2, 2, 2).into(), (0, 0, 0).into());
+        let b = Cuboid::new((1, 1, 1).into(), (3, 3, 3).into());
+        let c = Cuboid::new((3, 0, 0).into(), (3, 0, 0).into());
+        assert_eq!(a.min, ICoordinate3::ORIGIN);
+        assert_eq!(a.volume(), 27);
+        assert!(a.contains(&(2, 0, 1).into()));
+        assert!(!a.contains(&(3, 0, 1).into()));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::new((1, 1, 1).into(), (2, 2, 2).into()))
+        );
+        assert!(!a.intersects(&c));
+        assert!(a.translate((1, 0, 0).into()).intersects(&c));
+    }
+}
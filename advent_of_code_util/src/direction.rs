@@ -0,0 +1,304 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::icoordinate::ICoordinate;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RightOrLeft {
+    Right,
+    Left,
+}
+
+impl Direction {
+    /**
+    Every direction, clockwise from up
+    */
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Self::ALL.into_iter()
+    }
+
+    fn clockwise_index(self) -> usize {
+        match self {
+            Direction::Up => 0,
+            Direction::Right => 1,
+            Direction::Down => 2,
+            Direction::Left => 3,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.clockwise_index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.clockwise_index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.clockwise_index() + 2) % 4]
+    }
+
+    pub fn turn(self, towards: RightOrLeft) -> Self {
+        match towards {
+            RightOrLeft::Right => self.turn_right(),
+            RightOrLeft::Left => self.turn_left(),
+        }
+    }
+
+    /**
+    Which way to turn to face `next`, or `None` if it's straight ahead or behind
+    */
+    pub fn turn_to(self, next: Direction) -> Option<RightOrLeft> {
+        if next == self.turn_right() {
+            Some(RightOrLeft::Right)
+        } else if next == self.turn_left() {
+            Some(RightOrLeft::Left)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /**
+    One step as `(dx, dy)`, with `y` growing downwards like the rows of a grid
+    */
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Right => (1, 0),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    pub fn unit(self) -> ICoordinate {
+        let (x, y) = self.delta();
+        ICoordinate { x, y }
+    }
+}
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /**
+    Understands arrows (`^v<>`), letters (`UDLR`) and compass points (`NESW`)
+    */
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            _ => Err(format!("Unknown direction '{}'", c)),
+        }
+    }
+}
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("Unknown direction \"{}\"", s)),
+        }
+    }
+}
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Right => '>',
+            Direction::Left => '<',
+        };
+        write!(f, "{}", arrow)
+    }
+}
+
+/**
+The eight ways to move to a neighbouring cell, including diagonals
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+impl Direction8 {
+    /**
+    Every direction, clockwise from up
+    */
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Self::ALL.into_iter()
+    }
+
+    fn clockwise_index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&direction| direction == self)
+            .unwrap()
+    }
+
+    /**
+    Turns 45 degrees clockwise
+    */
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.clockwise_index() + 1) % 8]
+    }
+
+    /**
+    Turns 45 degrees anticlockwise
+    */
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.clockwise_index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.clockwise_index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.clockwise_index() % 2 == 1
+    }
+
+    /**
+    One step as `(dx, dy)`, with `y` growing downwards like the rows of a grid
+    */
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn unit(self) -> ICoordinate {
+        let (x, y) = self.delta();
+        ICoordinate { x, y }
+    }
+}
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Right => Direction8::Right,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+impl FromStr for Direction8 {
+    type Err = String;
+
+    /**
+    Understands everything `Direction` does, plus the compass points `NE`, `SE`, `SW` and `NW`
+    */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::UpRight),
+            "SE" => Ok(Direction8::DownRight),
+            "SW" => Ok(Direction8::DownLeft),
+            "NW" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::Down.turn(RightOrLeft::Right), Direction::Left);
+        assert_eq!(
+            Direction::Right.turn_to(Direction::Down),
+            Some(RightOrLeft::Right)
+        );
+        assert_eq!(Direction::Right.turn_to(Direction::Left), None);
+        assert!(Direction::iter().all(|d| d.turn_left().turn_right() == d));
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+        assert_eq!(Direction8::iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+
+    #[test]
+    fn deltas_sum_to_zero() {
+        let sum = |units: Vec<ICoordinate>| {
+            units
+                .into_iter()
+                .fold(ICoordinate { x: 0, y: 0 }, |a, b| a + b)
+        };
+        assert_eq!(
+            sum(Direction::iter().map(Direction::unit).collect()),
+            ICoordinate { x: 0, y: 0 }
+        );
+        assert_eq!(
+            sum(Direction8::iter().map(Direction8::unit).collect()),
+            ICoordinate { x: 0, y: 0 }
+        );
+        assert_eq!(Direction::Up.delta(), (0, -1));
+        assert_eq!(Direction8::DownLeft.delta(), (-1, 1));
+    }
+
+    #[test]
+    fn parses() {
+        for (chars, direction) in [
+            ("^UN", Direction::Up),
+            ("vDS", Direction::Down),
+            (">RE", Direction::Right),
+            ("<LW", Direction::Left),
+        ] {
+            assert!(chars
+                .chars()
+                .all(|c| Direction::try_from(c) == Ok(direction)));
+        }
+        assert!(Direction::try_from('x').is_err());
+        assert_eq!(Direction::Left.to_string().parse(), Ok(Direction::Left));
+        assert!("UD".parse::<Direction>().is_err());
+        assert_eq!("SW".parse(), Ok(Direction8::DownLeft));
+        assert_eq!("E".parse(), Ok(Direction8::Right));
+    }
+}
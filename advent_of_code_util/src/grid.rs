@@ -0,0 +1,339 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::Coordinate;
+
+/**
+A rectangular grid stored row by row, indexed by `Coordinate` so `x` is always the column and `y`
+the row
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+impl<T> Grid<T> {
+    /**
+    Builds a grid by working out each cell from its coordinate, row by row
+    */
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Coordinate) -> T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| Coordinate { x, y }))
+                .map(f)
+                .collect(),
+        }
+    }
+}
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = String;
+
+    /**
+    Builds a grid from rows like the ones the parse helpers return, which must all be as long as the
+    first
+    */
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(format!(
+                "Row {} has {} cells, expected {}",
+                y,
+                row.len(),
+                width
+            ));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        coordinate.x < self.width && coordinate.y < self.height
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        if self.contains(coordinate) {
+            Some(&self.cells[coordinate.y * self.width + coordinate.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        if self.contains(coordinate) {
+            Some(&mut self.cells[coordinate.y * self.width + coordinate.x])
+        } else {
+            None
+        }
+    }
+
+    /**
+    Every coordinate in the grid, row by row
+    */
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> + use<T> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Coordinate { x, y }))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> Column<'_, T> {
+        assert!(
+            x < self.width,
+            "Column {} is outside a {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+        Column { grid: self, x }
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /**
+    Every cell with its coordinate, row by row
+    */
+    pub fn enumerate(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.coordinates().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /**
+    The first coordinate, row by row, whose cell matches `predicate`
+    */
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Coordinate> {
+        self.enumerate()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coordinate, _)| coordinate)
+    }
+
+    /**
+    The up to four neighbours of `coordinate` that are inside the grid
+    */
+    pub fn neighbours(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + use<T> {
+        let (width, height) = (self.width, self.height);
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter_map(move |delta| offset(coordinate, delta, width, height))
+    }
+
+    /**
+    The up to eight neighbours of `coordinate`, including diagonals, that are inside the grid
+    */
+    pub fn neighbours_with_diagonals(
+        &self,
+        coordinate: Coordinate,
+    ) -> impl Iterator<Item = Coordinate> + use<T> {
+        let (width, height) = (self.width, self.height);
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .into_iter()
+        .filter_map(move |delta| offset(coordinate, delta, width, height))
+    }
+}
+impl<T: PartialEq> Grid<T> {
+    pub fn position_of(&self, value: &T) -> Option<Coordinate> {
+        self.find(|cell| cell == value)
+    }
+}
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        assert!(
+            self.contains(coordinate),
+            "{:?} is outside a {}x{} grid",
+            coordinate,
+            self.width,
+            self.height
+        );
+        &self.cells[coordinate.y * self.width + coordinate.x]
+    }
+}
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        assert!(
+            self.contains(coordinate),
+            "{:?} is outside a {}x{} grid",
+            coordinate,
+            self.width,
+            self.height
+        );
+        &mut self.cells[coordinate.y * self.width + coordinate.x]
+    }
+}
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/**
+One column of a grid, borrowed rather than copied out
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct Column<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+}
+impl<'a, T> Column<'a, T> {
+    pub fn len(&self) -> usize {
+        self.grid.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.grid.height == 0
+    }
+
+    /**
+    The cells from top to bottom
+    */
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + use<'a, T> {
+        let (grid, x) = (self.grid, self.x);
+        (0..grid.height).map(move |y| &grid[Coordinate { x, y }])
+    }
+}
+impl<T> Index<usize> for Column<'_, T> {
+    type Output = T;
+
+    fn index(&self, y: usize) -> &Self::Output {
+        &self.grid[Coordinate { x: self.x, y }]
+    }
+}
+impl<T: PartialEq> PartialEq for Column<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+fn offset(
+    coordinate: Coordinate,
+    (dx, dy): (isize, isize),
+    width: usize,
+    height: usize,
+) -> Option<Coordinate> {
+    let x = coordinate.x.checked_add_signed(dx).filter(|&x| x < width)?;
+    let y = coordinate
+        .y
+        .checked_add_signed(dy)
+        .filter(|&y| y < height)?;
+    Some(Coordinate { x, y })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::try_from(vec!["#S.".chars().collect(), "..E".chars().collect()]).unwrap()
+    }
+
+    #[test]
+    fn indexes_by_column_then_row() {
+        let mut grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coordinate { x: 2, y: 1 }], 'E');
+        assert_eq!(grid.get(Coordinate { x: 3, y: 0 }), None);
+        assert_eq!(grid.position_of(&'S'), Some(Coordinate { x: 1, y: 0 }));
+        assert_eq!(grid.find(|&c| c == 'X'), None);
+        grid[Coordinate { x: 0, y: 1 }] = '#';
+        assert_eq!(grid.to_string(), "#S.\n#.E");
+        assert_eq!(
+            grid.map(|&c| c == '#').iter().filter(|&&wall| wall).count(),
+            2
+        );
+        assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), &['.', '.', 'E']);
+        assert_eq!(grid.column(2).iter().collect::<String>(), ".E");
+        assert_eq!(grid.column(2)[1], 'E');
+        assert!(grid.column(0) != grid.column(1));
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(Grid::from_fn(2, 2, |c| c.x + 2 * c.y).to_string(), "01\n23");
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = example();
+        let corner = Coordinate { x: 0, y: 0 };
+        assert_eq!(
+            grid.neighbours(corner).collect::<Vec<_>>(),
+            vec![Coordinate { x: 1, y: 0 }, Coordinate { x: 0, y: 1 }]
+        );
+        assert_eq!(grid.neighbours_with_diagonals(corner).count(), 3);
+        assert_eq!(
+            grid.neighbours_with_diagonals(Coordinate { x: 1, y: 1 })
+                .count(),
+            5
+        );
+        assert_eq!(
+            grid.enumerate().nth(4),
+            Some((Coordinate { x: 1, y: 1 }, &'.'))
+        );
+    }
+}
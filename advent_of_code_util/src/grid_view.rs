@@ -0,0 +1,226 @@
+use crate::{grid::Grid, icoordinate::ICoordinate, Coordinate, Direction};
+
+/**
+Read-only lookups into something grid shaped, which might be bigger than the data behind it. Cells
+are returned by value, since a view can compute them rather than store them.
+*/
+pub trait GridView {
+    type Cell;
+
+    /**
+    The cell at `coordinate`, or `None` if the view doesn't reach that far
+    */
+    fn get(&self, coordinate: ICoordinate) -> Option<Self::Cell>;
+
+    /**
+    Where stepping from `coordinate` in `direction` ends up, if the view reaches there
+    */
+    fn step(&self, coordinate: ICoordinate, direction: Direction) -> Option<ICoordinate> {
+        let next = coordinate + direction;
+        self.get(next).map(|_| next)
+    }
+
+    /**
+    The up to four neighbours of `coordinate` that are inside the view
+    */
+    fn neighbours(&self, coordinate: ICoordinate) -> impl Iterator<Item = ICoordinate> {
+        Direction::iter().filter_map(move |direction| self.step(coordinate, direction))
+    }
+}
+
+impl<T: Clone> GridView for Grid<T> {
+    type Cell = T;
+
+    fn get(&self, coordinate: ICoordinate) -> Option<T> {
+        self.get(Coordinate::try_from(coordinate).ok()?).cloned()
+    }
+}
+
+/**
+Where `coordinate` lands once wrapped into the grid, or `None` if the grid is empty and there's
+nowhere to land
+*/
+fn wrapped<T>(grid: &Grid<T>, coordinate: ICoordinate) -> Option<Coordinate> {
+    if grid.width() == 0 || grid.height() == 0 {
+        return None;
+    }
+    let wrapped = coordinate.wrap(grid.width() as isize, grid.height() as isize);
+    Some(Coordinate::try_from(wrapped).expect("wrapping is never negative"))
+}
+
+/**
+The grid repeated forever in every direction
+*/
+pub struct Infinite<'a, T> {
+    grid: &'a Grid<T>,
+}
+impl<T: Clone> GridView for Infinite<'_, T> {
+    type Cell = T;
+
+    fn get(&self, coordinate: ICoordinate) -> Option<T> {
+        Some(self.grid[wrapped(self.grid, coordinate)?].clone())
+    }
+}
+
+/**
+The grid with its opposite edges joined, so walking off one side comes back on the other without
+ever leaving the grid's own coordinates. Looking up a coordinate outside the grid gives `None`, the
+same as for the grid itself.
+*/
+pub struct Toroidal<'a, T> {
+    grid: &'a Grid<T>,
+}
+impl<T: Clone> GridView for Toroidal<'_, T> {
+    type Cell = T;
+
+    fn get(&self, coordinate: ICoordinate) -> Option<T> {
+        GridView::get(self.grid, coordinate)
+    }
+
+    fn step(&self, coordinate: ICoordinate, direction: Direction) -> Option<ICoordinate> {
+        self.get(coordinate)?;
+        let next =
+            (coordinate + direction).wrap(self.grid.width() as isize, self.grid.height() as isize);
+        Some(next)
+    }
+}
+
+/**
+The grid repeated `columns` times across and `rows` times down, with `transform` applied to each
+cell given the position of the tile it's in
+*/
+pub struct Tiled<'a, T, F> {
+    grid: &'a Grid<T>,
+    columns: usize,
+    rows: usize,
+    transform: F,
+}
+impl<T, F> Tiled<'_, T, F> {
+    pub fn width(&self) -> usize {
+        self.grid.width() * self.columns
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height() * self.rows
+    }
+}
+impl<T, U, F: Fn(&T, Coordinate) -> U> GridView for Tiled<'_, T, F> {
+    type Cell = U;
+
+    fn get(&self, coordinate: ICoordinate) -> Option<U> {
+        let coordinate = Coordinate::try_from(coordinate).ok()?;
+        if coordinate.x >= self.width() || coordinate.y >= self.height() {
+            return None;
+        }
+        let tile = Coordinate {
+            x: coordinate.x / self.grid.width(),
+            y: coordinate.y / self.grid.height(),
+        };
+        let inner = Coordinate {
+            x: coordinate.x % self.grid.width(),
+            y: coordinate.y % self.grid.height(),
+        };
+        Some((self.transform)(&self.grid[inner], tile))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn infinite(&self) -> Infinite<'_, T> {
+        Infinite { grid: self }
+    }
+
+    pub fn toroidal(&self) -> Toroidal<'_, T> {
+        Toroidal { grid: self }
+    }
+
+    pub fn tiled(&self, columns: usize, rows: usize) -> Tiled<'_, T, fn(&T, Coordinate) -> T>
+    where
+        T: Clone,
+    {
+        self.tiled_with(columns, rows, |cell, _| cell.clone())
+    }
+
+    /**
+    Tiles the grid like `tiled`, working out each cell from the original and the tile it's in
+    */
+    pub fn tiled_with<U, F: Fn(&T, Coordinate) -> U>(
+        &self,
+        columns: usize,
+        rows: usize,
+        transform: F,
+    ) -> Tiled<'_, T, F> {
+        Tiled {
+            grid: self,
+            columns,
+            rows,
+            transform,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<usize> {
+        Grid::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    fn at(x: isize, y: isize) -> ICoordinate {
+        ICoordinate { x, y }
+    }
+
+    #[test]
+    fn grid_is_a_view() {
+        let grid = example();
+        assert_eq!(GridView::get(&grid, at(2, 1)), Some(6));
+        assert_eq!(GridView::get(&grid, at(-1, 0)), None);
+        assert_eq!(GridView::neighbours(&grid, at(0, 0)).count(), 2);
+    }
+
+    #[test]
+    fn infinite_repeats_everywhere() {
+        let grid = example();
+        let infinite = grid.infinite();
+        assert_eq!(infinite.get(at(-1, -1)), Some(6));
+        assert_eq!(infinite.get(at(3000, 2)), Some(1));
+        assert_eq!(
+            infinite.neighbours(at(0, 0)).collect::<Vec<_>>(),
+            vec![at(0, -1), at(1, 0), at(0, 1), at(-1, 0)]
+        );
+    }
+
+    #[test]
+    fn empty_grids_have_no_cells() {
+        let empty: Grid<usize> = Grid::new(0, 0, 0);
+        assert_eq!(empty.infinite().get(at(1, 1)), None);
+        assert_eq!(empty.toroidal().neighbours(at(0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn toroidal_stays_inside() {
+        let grid = example();
+        let torus = grid.toroidal();
+        assert_eq!(
+            torus.neighbours(at(0, 0)).collect::<Vec<_>>(),
+            vec![at(0, 1), at(1, 0), at(0, 1), at(2, 0)]
+        );
+        assert_eq!(torus.step(at(2, 1), Direction::Right), Some(at(0, 1)));
+        assert_eq!(torus.get(at(3, 0)), None);
+        assert_eq!(torus.step(at(3, 0), Direction::Right), None);
+    }
+
+    #[test]
+    fn tiles_with_transform() {
+        let grid = example();
+        let tiled = grid.tiled(2, 1);
+        assert_eq!((tiled.width(), tiled.height()), (6, 2));
+        assert_eq!(tiled.get(at(4, 1)), Some(5));
+        assert_eq!(tiled.get(at(6, 0)), None);
+        assert_eq!(tiled.neighbours(at(5, 1)).count(), 2);
+
+        let shifted = grid.tiled_with(2, 2, |cell, tile| cell + 10 * (tile.x + tile.y));
+        assert_eq!(shifted.get(at(1, 0)), Some(2));
+        assert_eq!(shifted.get(at(4, 3)), Some(25));
+    }
+}
@@ -0,0 +1,375 @@
+use std::{
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::parse_components;
+
+/**
+The six axial offsets, anticlockwise starting from `q + 1`. Both direction sets are just names for
+these.
+*/
+const AXIAL_OFFSETS: [(isize, isize); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+/**
+A hexagon in axial coordinates. The implied third cube coordinate is `s = -q - r`, so the three
+always sum to zero.
+*/
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+impl FromStr for Hex {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [q, r] = parse_components(s, "q,r")?;
+        Ok(Hex { q, r })
+    }
+}
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    /**
+    Builds a hex from cube coordinates, which must sum to zero
+    */
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Result<Hex, String> {
+        if q + r + s == 0 {
+            Ok(Hex { q, r })
+        } else {
+            Err(format!(
+                "Cube coordinates {},{},{} don't sum to zero",
+                q, r, s
+            ))
+        }
+    }
+
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Hex> {
+        AXIAL_OFFSETS
+            .into_iter()
+            .map(move |offset| self + Hex::from(offset))
+    }
+
+    /**
+    The number of steps between the two, moving between hexes that share an edge
+    */
+    pub fn distance(&self, other: &Hex) -> usize {
+        let difference = *self - *other;
+        (difference.q.unsigned_abs() + difference.r.unsigned_abs() + difference.s().unsigned_abs())
+            / 2
+    }
+
+    /**
+    The `6 * radius` hexes exactly `radius` steps away, going round anticlockwise. A radius of zero
+    is just this hex.
+    */
+    pub fn ring(self, radius: usize) -> impl Iterator<Item = Hex> {
+        let radius = radius as isize;
+        let corner = self + Hex::from(AXIAL_OFFSETS[4]) * radius;
+        let sides = if radius == 0 { 1 } else { 6 };
+        (0..sides)
+            .flat_map(move |side| (0..radius.max(1)).map(move |step| (side, step)))
+            .scan(corner, |hex, (side, _)| {
+                let current = *hex;
+                *hex = current + Hex::from(AXIAL_OFFSETS[side]);
+                Some(current)
+            })
+    }
+
+    /**
+    Every hex up to `radius` steps away, ring by ring starting with this one
+    */
+    pub fn spiral(self, radius: usize) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |ring| self.ring(ring))
+    }
+
+    /**
+    The centre of the hex in pixels, for hexes `size` pixels from centre to corner, with `y` growing
+    downwards
+    */
+    pub fn to_pixel(&self, orientation: Orientation, size: f64) -> (f64, f64) {
+        let sqrt_3 = 3f64.sqrt();
+        let (q, r) = (self.q as f64, self.r as f64);
+        match orientation {
+            Orientation::FlatTop => (size * 1.5 * q, size * (sqrt_3 / 2.0 * q + sqrt_3 * r)),
+            Orientation::PointyTop => (size * (sqrt_3 * q + sqrt_3 / 2.0 * r), size * 1.5 * r),
+        }
+    }
+}
+impl From<(isize, isize)> for Hex {
+    fn from((q, r): (isize, isize)) -> Self {
+        Hex { q, r }
+    }
+}
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Hex {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+        }
+    }
+}
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Hex {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+        }
+    }
+}
+impl Mul<isize> for Hex {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Hex {
+            q: self.q * rhs,
+            r: self.r * rhs,
+        }
+    }
+}
+impl Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Hex {
+            q: -self.q,
+            r: -self.r,
+        }
+    }
+}
+
+/**
+Which way up the hexagons are drawn
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Orientation {
+    FlatTop,
+    PointyTop,
+}
+
+/**
+The six ways out of a flat-topped hex, which has neighbours straight up and down
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum FlatDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+impl FlatDirection {
+    /**
+    Every direction, clockwise from north
+    */
+    pub const ALL: [FlatDirection; 6] = [
+        FlatDirection::North,
+        FlatDirection::NorthEast,
+        FlatDirection::SouthEast,
+        FlatDirection::South,
+        FlatDirection::SouthWest,
+        FlatDirection::NorthWest,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = FlatDirection> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn opposite(self) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap();
+        Self::ALL[(index + 3) % 6]
+    }
+
+    pub fn unit(self) -> Hex {
+        Hex::from(match self {
+            FlatDirection::North => (0, -1),
+            FlatDirection::NorthEast => (1, -1),
+            FlatDirection::SouthEast => (1, 0),
+            FlatDirection::South => (0, 1),
+            FlatDirection::SouthWest => (-1, 1),
+            FlatDirection::NorthWest => (-1, 0),
+        })
+    }
+}
+impl FromStr for FlatDirection {
+    type Err = String;
+
+    /**
+    Understands `n`, `ne`, `se`, `s`, `sw` and `nw`, in either case
+    */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "n" => Ok(FlatDirection::North),
+            "ne" => Ok(FlatDirection::NorthEast),
+            "se" => Ok(FlatDirection::SouthEast),
+            "s" => Ok(FlatDirection::South),
+            "sw" => Ok(FlatDirection::SouthWest),
+            "nw" => Ok(FlatDirection::NorthWest),
+            _ => Err(format!("Unknown hex direction \"{}\"", s)),
+        }
+    }
+}
+impl Add<FlatDirection> for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: FlatDirection) -> Self::Output {
+        self + rhs.unit()
+    }
+}
+
+/**
+The six ways out of a pointy-topped hex, which has neighbours straight left and right
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum PointyDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+impl PointyDirection {
+    /**
+    Every direction, clockwise from east
+    */
+    pub const ALL: [PointyDirection; 6] = [
+        PointyDirection::East,
+        PointyDirection::SouthEast,
+        PointyDirection::SouthWest,
+        PointyDirection::West,
+        PointyDirection::NorthWest,
+        PointyDirection::NorthEast,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = PointyDirection> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn opposite(self) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap();
+        Self::ALL[(index + 3) % 6]
+    }
+
+    pub fn unit(self) -> Hex {
+        Hex::from(match self {
+            PointyDirection::East => (1, 0),
+            PointyDirection::SouthEast => (0, 1),
+            PointyDirection::SouthWest => (-1, 1),
+            PointyDirection::West => (-1, 0),
+            PointyDirection::NorthWest => (0, -1),
+            PointyDirection::NorthEast => (1, -1),
+        })
+    }
+
+    /**
+    Splits a run of directions with no separators, like `esenee`, which is unambiguous because `n`
+    and `s` are always followed by `e` or `w`
+    */
+    pub fn parse_path(s: &str) -> Result<Vec<PointyDirection>, String> {
+        let mut directions = vec![];
+        let mut rest = s.trim();
+        while !rest.is_empty() {
+            let length = if rest.starts_with(['n', 's', 'N', 'S']) {
+                2
+            } else {
+                1
+            };
+            let token = rest
+                .get(..length)
+                .ok_or_else(|| format!("Path \"{}\" ends part way through a direction", s))?;
+            directions.push(token.parse()?);
+            rest = &rest[length..];
+        }
+        Ok(directions)
+    }
+}
+impl FromStr for PointyDirection {
+    type Err = String;
+
+    /**
+    Understands `e`, `se`, `sw`, `w`, `nw` and `ne`, in either case
+    */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "e" => Ok(PointyDirection::East),
+            "se" => Ok(PointyDirection::SouthEast),
+            "sw" => Ok(PointyDirection::SouthWest),
+            "w" => Ok(PointyDirection::West),
+            "nw" => Ok(PointyDirection::NorthWest),
+            "ne" => Ok(PointyDirection::NorthEast),
+            _ => Err(format!("Unknown hex direction \"{}\"", s)),
+        }
+    }
+}
+impl Add<PointyDirection> for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: PointyDirection) -> Self::Output {
+        self + rhs.unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn walks_and_measures() {
+        let walk = |path: &str| {
+            path.split(',')
+                .map(|token| token.parse::<FlatDirection>().unwrap())
+                .fold(Hex::ORIGIN, |hex, direction| hex + direction)
+        };
+        assert_eq!(walk("ne,ne,ne").distance(&Hex::ORIGIN), 3);
+        assert_eq!(walk("ne,ne,sw,sw"), Hex::ORIGIN);
+        assert_eq!(walk("ne,ne,s,s").distance(&Hex::ORIGIN), 2);
+        assert_eq!(walk("se,sw,se,sw,sw").distance(&Hex::ORIGIN), 3);
+        assert!(FlatDirection::iter().all(|d| Hex::ORIGIN + d + d.opposite() == Hex::ORIGIN));
+        assert!("e".parse::<FlatDirection>().is_err());
+
+        let tile = PointyDirection::parse_path("nwwswee")
+            .unwrap()
+            .into_iter()
+            .fold(Hex::ORIGIN, |hex, direction| hex + direction);
+        assert_eq!(tile, Hex::ORIGIN);
+        assert!(PointyDirection::parse_path("esen").is_err());
+        assert_eq!(Hex::from_cube(1, 2, -3), Ok(Hex { q: 1, r: 2 }));
+        assert!(Hex::from_cube(1, 2, 3).is_err());
+    }
+
+    #[test]
+    fn rings_and_spirals() {
+        let centre = Hex { q: 2, r: -1 };
+        assert_eq!(centre.ring(0).collect::<Vec<_>>(), vec![centre]);
+        let ring = centre.ring(2).collect::<Vec<_>>();
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|hex| hex.distance(&centre) == 2));
+        assert!(ring.windows(2).all(|pair| pair[0].distance(&pair[1]) == 1));
+        assert_eq!(centre.spiral(3).count(), 1 + 6 + 12 + 18);
+        assert_eq!(
+            centre.neighbours().sorted().collect::<Vec<_>>(),
+            centre.ring(1).sorted().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn pixels() {
+        let (x, y) = Hex { q: 1, r: 0 }.to_pixel(Orientation::PointyTop, 2.0);
+        assert!((x - 2.0 * 3f64.sqrt()).abs() < 1e-9 && y == 0.0);
+        let (x, y) = Hex { q: 0, r: 1 }.to_pixel(Orientation::FlatTop, 2.0);
+        assert!(x == 0.0 && (y - 2.0 * 3f64.sqrt()).abs() < 1e-9);
+    }
+}
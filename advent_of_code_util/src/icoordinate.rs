@@ -1,9 +1,9 @@
 use std::{
-    ops::{Add, Sub},
+    ops::{Add, Mul, Neg, Sub},
     str::FromStr,
 };
 
-use itertools::Itertools;
+use crate::{Coordinate, Direction, Direction8};
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
 pub struct ICoordinate {
@@ -11,17 +11,87 @@ pub struct ICoordinate {
     pub y: isize,
 }
 impl FromStr for ICoordinate {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s
-            .split(',')
-            .map(|num| num.parse::<isize>().unwrap())
-            .collect_tuple::<(isize, isize)>()
-            .unwrap();
+        let [x, y] = crate::parse_components(s, "x,y")?;
         Ok(ICoordinate { x, y })
     }
 }
+impl ICoordinate {
+    /**
+    The four neighbours in the order of `Direction::ALL`
+    */
+    pub fn neighbours(self) -> impl Iterator<Item = ICoordinate> {
+        Direction::iter().map(move |direction| self + direction)
+    }
+
+    /**
+    The eight neighbours, including diagonals, in the order of `Direction8::ALL`
+    */
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = ICoordinate> {
+        Direction8::iter().map(move |direction| self + direction.unit())
+    }
+
+    pub fn manhattan_distance(&self, other: &ICoordinate) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /**
+    The number of king's moves between the two, where a diagonal step counts as one
+    */
+    pub fn chebyshev_distance(&self, other: &ICoordinate) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /**
+    Wraps into `0..width` and `0..height`, as if the plane were a torus of that size
+    */
+    pub fn wrap(&self, width: isize, height: isize) -> ICoordinate {
+        ICoordinate {
+            x: self.x.rem_euclid(width),
+            y: self.y.rem_euclid(height),
+        }
+    }
+
+    /**
+    Rotates 90 degrees clockwise around the origin, with `y` growing downwards so `Up` becomes
+    `Right`
+    */
+    pub fn rotate_right(&self) -> ICoordinate {
+        ICoordinate {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    pub fn rotate_left(&self) -> ICoordinate {
+        ICoordinate {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+impl TryFrom<Coordinate> for ICoordinate {
+    type Error = String;
+
+    fn try_from(coordinate: Coordinate) -> Result<Self, Self::Error> {
+        match (isize::try_from(coordinate.x), isize::try_from(coordinate.y)) {
+            (Ok(x), Ok(y)) => Ok(ICoordinate { x, y }),
+            _ => Err(format!("{:?} is too big for an ICoordinate", coordinate)),
+        }
+    }
+}
+impl TryFrom<ICoordinate> for Coordinate {
+    type Error = String;
+
+    fn try_from(coordinate: ICoordinate) -> Result<Self, Self::Error> {
+        match (usize::try_from(coordinate.x), usize::try_from(coordinate.y)) {
+            (Ok(x), Ok(y)) => Ok(Coordinate { x, y }),
+            _ => Err(format!("{:?} is negative", coordinate)),
+        }
+    }
+}
 impl Add for ICoordinate {
     type Output = Self;
 
@@ -42,3 +112,77 @@ impl Sub for ICoordinate {
         }
     }
 }
+impl Add<Direction> for ICoordinate {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.unit()
+    }
+}
+impl Mul<isize> for ICoordinate {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        ICoordinate {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+impl Neg for ICoordinate {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        ICoordinate {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGIN: ICoordinate = ICoordinate { x: 0, y: 0 };
+
+    #[test]
+    fn neighbours_and_distances() {
+        let point = ICoordinate { x: 2, y: -3 };
+        assert_eq!(point.neighbours().count(), 4);
+        assert!(point
+            .neighbours_with_diagonals()
+            .all(|neighbour| point.chebyshev_distance(&neighbour) == 1));
+        assert_eq!(point.neighbours().next(), Some(ICoordinate { x: 2, y: -4 }));
+        assert_eq!(point.manhattan_distance(&ORIGIN), 5);
+        assert_eq!(point.chebyshev_distance(&ORIGIN), 3);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let point = ICoordinate { x: 2, y: -3 };
+        assert_eq!(point * 3, ICoordinate { x: 6, y: -9 });
+        assert_eq!(point + -point, ORIGIN);
+        assert_eq!(point.wrap(5, 5), ICoordinate { x: 2, y: 2 });
+        assert_eq!((point * 100).wrap(7, 11), ICoordinate { x: 4, y: 8 });
+        assert_eq!(Direction::Up.unit().rotate_right(), Direction::Right.unit());
+        assert_eq!(Direction::Up.unit().rotate_left(), Direction::Left.unit());
+        assert_eq!(
+            point.rotate_right().rotate_right().rotate_right(),
+            point.rotate_left()
+        );
+    }
+
+    #[test]
+    fn converts_checked() {
+        let point = ICoordinate { x: 2, y: 3 };
+        assert_eq!(Coordinate::try_from(point), Ok(Coordinate { x: 2, y: 3 }));
+        assert_eq!(ICoordinate::try_from(Coordinate { x: 2, y: 3 }), Ok(point));
+        assert!(Coordinate::try_from(-point).is_err());
+        assert!(ICoordinate::try_from(Coordinate {
+            x: usize::MAX,
+            y: 0
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use petgraph::graph::{DiGraph, NodeIndex};
+
+use crate::{grid::Grid, Coordinate};
+
+/**
+A maze shrunk down to its junctions, with an edge for each corridor between two of them weighted by
+how many steps it takes. Edges are directed, so a corridor that can be walked both ways has one edge
+each way.
+*/
+#[derive(Debug, Clone)]
+pub struct Junctions {
+    pub graph: DiGraph<Coordinate, usize>,
+    nodes: BTreeMap<Coordinate, NodeIndex>,
+}
+impl Junctions {
+    /**
+    The graph node for a junction or one of the extra points it was built with
+    */
+    pub fn node(&self, coordinate: Coordinate) -> Option<NodeIndex> {
+        self.nodes.get(&coordinate).copied()
+    }
+
+    pub fn coordinate(&self, node: NodeIndex) -> Coordinate {
+        self.graph[node]
+    }
+
+    /**
+    The length of the longest corridor straight from `from` to `to`, if there is one. Two junctions
+    can be joined by more than one corridor, and which one matters depends on the puzzle, so for the
+    shortest use the graph's own algorithms, which pick the cheapest edge.
+    */
+    pub fn longest_corridor(&self, from: NodeIndex, to: NodeIndex) -> Option<usize> {
+        self.graph
+            .edges_connecting(from, to)
+            .map(|edge| *edge.weight())
+            .max()
+    }
+
+    /**
+    The total length of a walk given as a list of nodes, taking the longest corridor from each to the
+    next
+    */
+    pub fn longest_walk(&self, path: &[NodeIndex]) -> Option<usize> {
+        path.windows(2)
+            .map(|pair| self.longest_corridor(pair[0], pair[1]))
+            .sum()
+    }
+}
+
+/**
+Contracts a maze to the cells with more than two ways in or out, plus the `extra` points such as the
+start and end. `can_step(from, to)` says whether one move between neighbouring cells is allowed,
+which covers both walls and one-way tiles. Corridors that dead-end before reaching a node are dropped.
+*/
+pub fn junctions<T>(
+    grid: &Grid<T>,
+    extra: impl IntoIterator<Item = Coordinate>,
+    can_step: impl Fn(Coordinate, Coordinate) -> bool,
+) -> Junctions {
+    let is_junction = |coordinate: Coordinate| {
+        grid.neighbours(coordinate)
+            .filter(|&n| can_step(coordinate, n) || can_step(n, coordinate))
+            .count()
+            > 2
+    };
+    let mut graph = DiGraph::new();
+    let mut nodes = BTreeMap::new();
+    for coordinate in extra
+        .into_iter()
+        .chain(grid.coordinates().filter(|&c| is_junction(c)))
+    {
+        nodes
+            .entry(coordinate)
+            .or_insert_with(|| graph.add_node(coordinate));
+    }
+
+    for (&from, &from_node) in &nodes {
+        for first in grid.neighbours(from).filter(|&n| can_step(from, n)) {
+            let (mut previous, mut current, mut length) = (from, first, 1);
+            while !nodes.contains_key(&current) {
+                let Some(next) = grid
+                    .neighbours(current)
+                    .find(|&n| n != previous && can_step(current, n))
+                else {
+                    break;
+                };
+                (previous, current, length) = (current, next, length + 1);
+            }
+            if let Some(&to_node) = nodes.get(&current) {
+                graph.add_edge(from_node, to_node, length);
+            }
+        }
+    }
+    Junctions { graph, nodes }
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::algo::dijkstra;
+
+    use super::*;
+
+    fn maze(rows: &[&str]) -> Grid<char> {
+        Grid::try_from(
+            rows.iter()
+                .map(|row| row.chars().collect())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn contracts_corridors() {
+        let grid = maze(&["#.#####", "#.....#", "#.###.#", "#.....#", "#####.#"]);
+        let (start, end) = (Coordinate { x: 1, y: 0 }, Coordinate { x: 5, y: 4 });
+        let open = |from: Coordinate, to: Coordinate| grid[from] != '#' && grid[to] != '#';
+        let junctions = junctions(&grid, [start, end], open);
+
+        assert_eq!(junctions.graph.node_count(), 4);
+        let corner = junctions.node(Coordinate { x: 1, y: 1 }).unwrap();
+        let (start, end) = (junctions.node(start).unwrap(), junctions.node(end).unwrap());
+        assert_eq!(junctions.longest_corridor(start, corner), Some(1));
+        assert_eq!(junctions.graph.edges_connecting(corner, start).count(), 1);
+        let costs = dijkstra(&junctions.graph, start, Some(end), |edge| *edge.weight());
+        assert_eq!(costs[&end], 8);
+    }
+
+    #[test]
+    fn respects_one_way_tiles() {
+        let grid = maze(&["#.###", "#>..#", "#.#.#", "#...#", "###.#"]);
+        let (start, end) = (Coordinate { x: 1, y: 0 }, Coordinate { x: 3, y: 4 });
+        let one_way = |from: Coordinate, to: Coordinate| {
+            grid[from] != '#'
+                && grid[to] != '#'
+                && (grid[from] != '>' || to.x > from.x)
+                && (grid[to] != '>' || to.x >= from.x)
+        };
+        let junctions = junctions(&grid, [start, end], one_way);
+        let left = junctions.node(Coordinate { x: 1, y: 1 }).unwrap();
+        let right = junctions.node(Coordinate { x: 3, y: 3 }).unwrap();
+        let start = junctions.node(start).unwrap();
+
+        assert_eq!(junctions.graph.edges_connecting(left, right).count(), 1);
+        assert_eq!(junctions.longest_corridor(left, right), Some(4));
+        assert_eq!(junctions.longest_corridor(right, left), Some(4));
+        assert_eq!(junctions.longest_walk(&[start, left, right]), Some(5));
+    }
+}
@@ -1,22 +1,51 @@
 use itertools::Itertools;
-use std::{collections::BTreeSet, str::FromStr};
+use std::{collections::BTreeSet, hash::Hash, str::FromStr};
 
+pub mod answers;
+pub mod bench;
+pub mod coordinate3;
+pub mod direction;
+pub mod grid;
+pub mod grid_view;
+pub mod hex;
 pub mod icoordinate;
+pub mod junctions;
+pub mod line;
 pub mod matrix;
+pub mod params;
 pub mod parse;
+pub mod region;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
-pub enum Direction {
-    Up,
-    Down,
-    Right,
-    Left,
-}
+pub use direction::{Direction, Direction8, RightOrLeft};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum RightOrLeft {
-    Right,
-    Left,
+/**
+Parses comma separated numbers like `x,y` or `x,y,z`, for the coordinate `FromStr` impls. `names`
+describes the expected format in errors.
+*/
+pub(crate) fn parse_components<T: FromStr, const N: usize>(
+    s: &str,
+    names: &str,
+) -> Result<[T; N], String> {
+    let components = s.split(',').collect_vec();
+    if components.len() != N {
+        return Err(format!("Expected {}, got \"{}\"", names, s));
+    }
+    components
+        .into_iter()
+        .map(|num| {
+            num.trim()
+                .parse()
+                .map_err(|_| format!("Invalid number \"{}\" in \"{}\"", num, s))
+        })
+        .collect::<Result<Vec<T>, _>>()
+        .map(|numbers| {
+            numbers
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("checked the length"))
+        })
 }
 
 pub fn abs_diff<T: Ord + std::ops::Sub<Output = T> + Copy>(slf: T, other: T) -> T {
@@ -29,14 +58,10 @@ pub struct Coordinate {
     pub y: usize,
 }
 impl FromStr for Coordinate {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s
-            .split(',')
-            .map(|num| num.parse::<usize>().unwrap())
-            .collect_tuple::<(usize, usize)>()
-            .unwrap();
+        let [x, y] = parse_components(s, "x,y")?;
         Ok(Coordinate { x, y })
     }
 }
@@ -92,29 +117,14 @@ impl Coordinate {
     }
 
     /**
-    assumes that from and to are either on a horizontal or vertical line
+    The neighbouring coordinate in `direction`, or `None` if that's off the top or left edge
     */
-    pub fn get_points_between_vertices(&self, to: &Coordinate) -> Vec<Coordinate> {
-        assert!(self.x == to.x || self.y == to.y);
-        match self.x.cmp(&to.x) {
-            std::cmp::Ordering::Less => (self.x..=to.x)
-                .map(|x| Coordinate { x, y: self.y })
-                .collect(),
-            std::cmp::Ordering::Equal => {
-                if self.y < to.y {
-                    (self.y..=to.y)
-                        .map(|y| Coordinate { x: self.x, y })
-                        .collect()
-                } else {
-                    (to.y..=self.y)
-                        .map(|y| Coordinate { x: self.x, y })
-                        .collect()
-                }
-            }
-            std::cmp::Ordering::Greater => (to.x..=self.x)
-                .map(|x| Coordinate { x, y: self.y })
-                .collect(),
-        }
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.delta();
+        Some(Coordinate {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
     }
 
     pub fn non_diagonal_distance(&self, other: &Coordinate) -> usize {
@@ -215,6 +225,37 @@ impl Coordinate {
     }
 }
 
+/**
+Either coordinate type, seen as a pair of signed components, for code like `SparseGrid` and `line`
+that works with both
+*/
+pub trait Point: Copy + Eq + Hash {
+    fn components(self) -> (isize, isize);
+
+    fn from_components(x: isize, y: isize) -> Self;
+}
+impl Point for Coordinate {
+    fn components(self) -> (isize, isize) {
+        (self.x as isize, self.y as isize)
+    }
+
+    fn from_components(x: isize, y: isize) -> Self {
+        Coordinate {
+            x: x.try_into().expect("Coordinate can't be negative"),
+            y: y.try_into().expect("Coordinate can't be negative"),
+        }
+    }
+}
+impl Point for icoordinate::ICoordinate {
+    fn components(self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    fn from_components(x: isize, y: isize) -> Self {
+        icoordinate::ICoordinate { x, y }
+    }
+}
+
 pub fn remove_first_and_last(string: &str) -> String {
     let mut chars = string.chars();
     chars.next();
@@ -227,60 +268,139 @@ pub fn intersect_vectors<T: std::cmp::Ord>(vecs: Vec<Vec<T>>) -> Vec<T> {
     let mut remaining = BTreeSet::from_iter(vec_iter.next().unwrap());
 
     for vec in vec_iter {
-        let vec_set = BTreeSet::from_iter(vec.into_iter());
+        let vec_set = BTreeSet::from_iter(vec);
         remaining.retain(|item| vec_set.contains(item));
     }
 
     remaining.into_iter().collect_vec()
 }
 
+/**
+Registers a day with the runner by exporting it as `DAY`, without generating any tests. With no
+arguments it registers `get_program_output`, otherwise a parse function and the two parts.
+*/
+#[macro_export]
+macro_rules! aoc_day {
+    () => {
+        pub static DAY: $crate::solution::Day = $crate::solution::Day {
+            name: env!("CARGO_PKG_NAME"),
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            solution: &(get_program_output as fn(&str) -> (_, _)),
+        };
+    };
+    ( $parse:ident, $part_1:ident, $part_2:ident ) => {
+        pub static DAY: $crate::solution::Day = $crate::solution::Day {
+            name: env!("CARGO_PKG_NAME"),
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            solution: &$crate::solution::Partwise {
+                parse: $parse,
+                part_1: $part_1,
+                part_2: $part_2,
+            },
+        };
+    };
+}
+
+/**
+Generates the example tests for a day and registers it with the runner. Either pass the two answers
+for `testinput`, or a list of named examples where each becomes its own test and either part can be
+left out. Examples can also override the day's `Param`s. Days split into parts start with
+`parts(parse, part_1, part_2);` instead of defining `get_program_output`, and then only run the
+parts an example has answers for:
+
+```ignore
+base_aoc!(
+    parts(parse, part_1, part_2);
+    main("testinput", params: [size = 7], part_1: 142),
+    #[ignore]
+    part_2_example("testinput2", part_2: 281),
+);
+```
+*/
 #[macro_export]
 macro_rules! base_aoc {
     ( $part_1_answer:literal, $part_2_answer:literal ) => {
+        $crate::base_aoc!(main("testinput", part_1: $part_1_answer, part_2: $part_2_answer));
+    };
+    ( parts($parse:ident, $part_1:ident, $part_2:ident); $($examples:tt)+ ) => {
+        $crate::base_aoc!(@tests ($parse, $part_1, $part_2) $($examples)+);
+        $crate::aoc_day!($parse, $part_1, $part_2);
+    };
+    (
+        @tests $solution:tt
+        $(
+            $(#[$attr:meta])*
+            $name:ident (
+                $file:literal
+                $(, params: [$($param:ident = $value:expr),* $(,)?])?
+                $(, part_1: $part_1_answer:expr)?
+                $(, part_2: $part_2_answer:expr)?
+                $(,)?
+            )
+        ),+ $(,)?
+    ) => {
         #[cfg(test)]
         mod tests {
             use super::*;
 
+            $(
+                $(#[$attr])*
+                #[test]
+                fn $name() {
+                    let file_path = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), $file);
+                    let overrides: $crate::params::Overrides =
+                        [$($((stringify!($param), $value.to_string())),*)?].into_iter().collect();
+                    $crate::params::with_overrides(&overrides, || {
+                        $crate::base_aoc!(
+                            @check $solution,
+                            &file_path,
+                            [$($part_1_answer)?],
+                            [$($part_2_answer)?]
+                        )
+                    })
+                    .unwrap();
+                }
+            )+
+
+            #[ignore = "solves the real input, run with --ignored"]
             #[test]
-            fn main() {
-                let file_path = format!("{}/testinput", env!("CARGO_MANIFEST_DIR"));
-                let (part_1_output, part_2_output) = get_program_output(&file_path);
-                assert_eq!(part_1_output, $part_1_answer);
-                assert_eq!(part_2_output, $part_2_answer);
+            fn real_input() {
+                $crate::answers::assert_verified(&DAY);
             }
         }
-
-        fn main() {
-            let file_path = format!("{}/input", env!("CARGO_MANIFEST_DIR"));
-            let (part_1_output, part_2_output) = get_program_output(&file_path);
-            println!("Part 1 output: {}", part_1_output);
-            println!("Part 2 output: {}", part_2_output);
-        }
+    };
+    (
+        @check (),
+        $file_path:expr,
+        [$($part_1_answer:expr)?],
+        [$($part_2_answer:expr)?]
+    ) => {{
+        let _outputs = get_program_output($file_path);
+        $(assert_eq!(_outputs.0, $part_1_answer, "part 1 of {}", $file_path);)?
+        $(assert_eq!(_outputs.1, $part_2_answer, "part 2 of {}", $file_path);)?
+    }};
+    (
+        @check ($parse:ident, $part_1:ident, $part_2:ident),
+        $file_path:expr,
+        [$($part_1_answer:expr)?],
+        [$($part_2_answer:expr)?]
+    ) => {{
+        let _input = $parse($file_path);
+        $(assert_eq!($part_1(&_input), $part_1_answer, "part 1 of {}", $file_path);)?
+        $(assert_eq!($part_2(&_input), $part_2_answer, "part 2 of {}", $file_path);)?
+    }};
+    ( $($examples:tt)+ ) => {
+        $crate::base_aoc!(@tests () $($examples)+);
+        $crate::aoc_day!();
     };
 }
 
 #[macro_export]
 macro_rules! base_aoc_ignore_tests {
     ( $part_1_answer:literal, $part_2_answer:literal ) => {
-        #[cfg(test)]
-        mod tests {
-            use super::*;
-
+        $crate::base_aoc!(
             #[ignore]
-            #[test]
-            fn main() {
-                let file_path = format!("{}/testinput", env!("CARGO_MANIFEST_DIR"));
-                let (part_1_output, part_2_output) = get_program_output(&file_path);
-                assert_eq!(part_1_output, $part_1_answer);
-                assert_eq!(part_2_output, $part_2_answer);
-            }
-        }
-
-        fn main() {
-            let file_path = format!("{}/input", env!("CARGO_MANIFEST_DIR"));
-            let (part_1_output, part_2_output) = get_program_output(&file_path);
-            println!("Part 1 output: {}", part_1_output);
-            println!("Part 2 output: {}", part_2_output);
-        }
+            main("testinput", part_1: $part_1_answer, part_2: $part_2_answer)
+        );
     };
 }
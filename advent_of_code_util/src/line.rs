@@ -0,0 +1,138 @@
+use crate::Point;
+
+/**
+The points on a straight line between two ends, including both, worked out one at a time with
+Bresenham's algorithm. Horizontal, vertical and 45 degree lines come out exact.
+*/
+#[derive(Debug, Clone)]
+pub struct Line<P> {
+    current: (isize, isize),
+    delta: (isize, isize),
+    step: (isize, isize),
+    error: isize,
+    remaining: usize,
+    point: std::marker::PhantomData<P>,
+}
+impl<P: Point> Iterator for Line<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (x, y) = self.current;
+        let ((dx, dy), (sx, sy)) = (self.delta, self.step);
+        let doubled = 2 * self.error;
+        if doubled >= dy {
+            self.error += dy;
+            self.current.0 += sx;
+        }
+        if doubled <= dx {
+            self.error += dx;
+            self.current.1 += sy;
+        }
+        Some(P::from_components(x, y))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<P: Point> ExactSizeIterator for Line<P> {}
+
+pub fn line<P: Point>(from: P, to: P) -> Line<P> {
+    let ((x0, y0), (x1, y1)) = (from.components(), to.components());
+    let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+    Line {
+        current: (x0, y0),
+        delta: (dx, dy),
+        step: ((x1 - x0).signum(), (y1 - y0).signum()),
+        error: dx + dy,
+        remaining: dx.max(-dy) as usize + 1,
+        point: std::marker::PhantomData,
+    }
+}
+
+/**
+Every point on the lines joining `vertices` in order, with each vertex only once
+*/
+pub fn polyline<P: Point>(vertices: impl IntoIterator<Item = P>) -> impl Iterator<Item = P> {
+    let vertices = vertices.into_iter().collect::<Vec<_>>();
+    let last = vertices.last().copied();
+    without_ends(vertices.windows(2).map(|pair| (pair[0], pair[1])).collect()).chain(last)
+}
+
+/**
+Every point on the edges of the polygon with these corners, going round once and coming back to
+just before the first
+*/
+pub fn polygon_edges<P: Point>(vertices: impl IntoIterator<Item = P>) -> impl Iterator<Item = P> {
+    let vertices = vertices.into_iter().collect::<Vec<_>>();
+    let edges = (0..vertices.len())
+        .map(|i| (vertices[i], vertices[(i + 1) % vertices.len()]))
+        .collect::<Vec<_>>();
+    let lone_vertex = (vertices.len() == 1).then(|| vertices[0]);
+    without_ends(edges).chain(lone_vertex)
+}
+
+/**
+The points on each line except its last, so joined up lines don't repeat the vertices they share
+*/
+fn without_ends<P: Point>(segments: Vec<(P, P)>) -> impl Iterator<Item = P> {
+    segments.into_iter().flat_map(|(from, to)| {
+        let line = line(from, to);
+        let length = line.len();
+        line.take(length - 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{icoordinate::ICoordinate, Coordinate};
+
+    fn at(x: isize, y: isize) -> ICoordinate {
+        ICoordinate { x, y }
+    }
+
+    #[test]
+    fn draws_lines() {
+        assert_eq!(
+            line(at(1, 1), at(1, 3)).collect_vec(),
+            vec![at(1, 1), at(1, 2), at(1, 3)]
+        );
+        assert_eq!(
+            line(Coordinate { x: 9, y: 7 }, Coordinate { x: 7, y: 9 }).collect_vec(),
+            vec![
+                Coordinate { x: 9, y: 7 },
+                Coordinate { x: 8, y: 8 },
+                Coordinate { x: 7, y: 9 }
+            ]
+        );
+        assert_eq!(line(at(-2, 0), at(-2, 0)).collect_vec(), vec![at(-2, 0)]);
+        let steep = line(at(0, 0), at(2, -5)).collect_vec();
+        assert_eq!(steep.len(), 6);
+        assert_eq!(steep.last(), Some(&at(2, -5)));
+        assert!(steep
+            .windows(2)
+            .all(|pair| pair[0].chebyshev_distance(&pair[1]) == 1));
+    }
+
+    #[test]
+    fn joins_lines_up() {
+        let corners = [at(0, 0), at(2, 0), at(2, 2)];
+        assert_eq!(
+            polyline(corners).collect_vec(),
+            vec![at(0, 0), at(1, 0), at(2, 0), at(2, 1), at(2, 2)]
+        );
+        let edges = polygon_edges(corners).collect_vec();
+        assert_eq!(edges.len(), 6);
+        assert_eq!(edges.iter().unique().count(), 6);
+        assert_eq!(edges.last(), Some(&at(1, 1)));
+        assert_eq!(polygon_edges([at(3, 3)]).collect_vec(), vec![at(3, 3)]);
+        assert_eq!(polyline(Vec::<ICoordinate>::new()).count(), 0);
+    }
+}
@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::{grid::Grid, Coordinate};
+
 pub fn transpose2<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
     assert!(!v.is_empty());
     let len = v[0].len();
@@ -17,3 +19,183 @@ pub fn transpose2<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
 pub fn clone_column<T: Clone>(v: &[Vec<T>], x: usize) -> Vec<T> {
     v.iter().map(|row| row[x].clone()).collect_vec()
 }
+
+/**
+Rearrangements of a whole `Grid`, each building a new grid
+*/
+impl<T: Clone> Grid<T> {
+    /**
+    Swaps rows and columns, mirroring along the top-left to bottom-right diagonal
+    */
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height(), self.width(), |c| {
+            self[Coordinate { x: c.y, y: c.x }].clone()
+        })
+    }
+
+    /**
+    Turns the grid 90 degrees clockwise, so the left column becomes the top row
+    */
+    pub fn rotate_right(&self) -> Grid<T> {
+        let height = self.height();
+        Grid::from_fn(height, self.width(), |c| {
+            self[Coordinate {
+                x: c.y,
+                y: height - 1 - c.x,
+            }]
+            .clone()
+        })
+    }
+
+    /**
+    Turns the grid 90 degrees anticlockwise, so the right column becomes the top row
+    */
+    pub fn rotate_left(&self) -> Grid<T> {
+        let width = self.width();
+        Grid::from_fn(self.height(), width, |c| {
+            self[Coordinate {
+                x: width - 1 - c.y,
+                y: c.x,
+            }]
+            .clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        Grid::from_fn(width, height, |c| {
+            self[Coordinate {
+                x: width - 1 - c.x,
+                y: height - 1 - c.y,
+            }]
+            .clone()
+        })
+    }
+
+    /**
+    Mirrors left to right
+    */
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width();
+        Grid::from_fn(width, self.height(), |c| {
+            self[Coordinate {
+                x: width - 1 - c.x,
+                y: c.y,
+            }]
+            .clone()
+        })
+    }
+
+    /**
+    Mirrors top to bottom
+    */
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height();
+        Grid::from_fn(self.width(), height, |c| {
+            self[Coordinate {
+                x: c.x,
+                y: height - 1 - c.y,
+            }]
+            .clone()
+        })
+    }
+
+    /**
+    The four rotations followed by the four rotations of the mirror image, which between them are
+    every way of laying the grid back down on itself
+    */
+    pub fn symmetries(&self) -> [Grid<T>; 8] {
+        let flipped = self.flip_horizontal();
+        [
+            self.clone(),
+            self.rotate_right(),
+            self.rotate_180(),
+            self.rotate_left(),
+            flipped.rotate_right(),
+            flipped.rotate_180(),
+            flipped.rotate_left(),
+            flipped,
+        ]
+    }
+
+    /**
+    A copy of the `width` by `height` block starting at `top_left`, or `None` if it doesn't fit
+    */
+    pub fn sub_grid(&self, top_left: Coordinate, width: usize, height: usize) -> Option<Grid<T>> {
+        if top_left.x + width > self.width() || top_left.y + height > self.height() {
+            return None;
+        }
+        Some(Grid::from_fn(width, height, |c| {
+            self[Coordinate {
+                x: top_left.x + c.x,
+                y: top_left.y + c.y,
+            }]
+            .clone()
+        }))
+    }
+
+    /**
+    Copies `other` over this grid with its top left corner at `top_left`, failing without changing
+    anything if it would hang off the edge
+    */
+    pub fn paste(&mut self, top_left: Coordinate, other: &Grid<T>) -> Result<(), String> {
+        if top_left.x + other.width() > self.width() || top_left.y + other.height() > self.height()
+        {
+            return Err(format!(
+                "A {}x{} grid at {:?} doesn't fit in a {}x{} grid",
+                other.width(),
+                other.height(),
+                top_left,
+                self.width(),
+                self.height()
+            ));
+        }
+        for (c, cell) in other.enumerate() {
+            self[Coordinate {
+                x: top_left.x + c.x,
+                y: top_left.y + c.y,
+            }] = cell.clone();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::try_from(vec![
+            "ab".chars().collect(),
+            "cd".chars().collect(),
+            "ef".chars().collect(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn rotates_and_flips() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+        assert_eq!(grid.rotate_right().to_string(), "eca\nfdb");
+        assert_eq!(grid.rotate_left().to_string(), "bdf\nace");
+        assert_eq!(grid.rotate_180().to_string(), "fe\ndc\nba");
+        assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe");
+        assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.symmetries().iter().unique().count(), 8);
+        assert!(grid.symmetries().contains(&grid.transpose()));
+        assert!(grid.symmetries().contains(&grid.flip_vertical()));
+    }
+
+    #[test]
+    fn cuts_and_pastes() {
+        let mut grid = example();
+        let corner = grid.sub_grid(Coordinate { x: 1, y: 1 }, 1, 2).unwrap();
+        assert_eq!(corner.to_string(), "d\nf");
+        assert_eq!(grid.sub_grid(Coordinate { x: 1, y: 1 }, 2, 1), None);
+        grid.paste(Coordinate { x: 0, y: 0 }, &corner).unwrap();
+        assert_eq!(grid.to_string(), "db\nfd\nef");
+        assert!(grid.paste(Coordinate { x: 1, y: 2 }, &corner).is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+/**
+A puzzle constant that differs between the examples and the real input, like a grid size or a number
+of steps. The default is the value for the real input, examples override it by name:
+
+```ignore
+const SIZE: Param<usize> = Param::new("size", 71);
+
+base_aoc!(main("testinput", params: [size = 7], part_1: 22));
+```
+*/
+pub struct Param<T> {
+    pub name: &'static str,
+    pub default: T,
+}
+impl<T: FromStr + Clone> Param<T> {
+    pub const fn new(name: &'static str, default: T) -> Self {
+        Param { name, default }
+    }
+
+    /**
+    The overridden value if `with_overrides` is running on this thread and sets it, otherwise the
+    default. Read it once per solve rather than in a hot loop.
+
+    Threads a solution spawns don't see the overrides and get the default, so read parameters
+    before spawning and move the values in. Overrides are per thread so that examples running as
+    parallel tests can't see each other's values.
+    */
+    pub fn get(&self) -> T {
+        ACTIVE.with(|active| {
+            let mut active = active.borrow_mut();
+            active.used.insert(self.name.to_string());
+            match active.values.get(self.name) {
+                Some(value) => value.parse().unwrap_or_else(|_| {
+                    panic!("Invalid value \"{}\" for parameter {}", value, self.name)
+                }),
+                None => self.default.clone(),
+            }
+        })
+    }
+}
+
+/**
+Parameter values by name, written on the command line as `name=value,other=value`
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Overrides(BTreeMap<String, String>);
+impl FromStr for Overrides {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.split_once('=') {
+                Some((name, value)) if !name.is_empty() => Ok((name, value.to_string())),
+                _ => Err(format!("Expected name=value, got \"{}\"", pair)),
+            })
+            .collect()
+    }
+}
+impl<'a> FromIterator<(&'a str, String)> for Overrides {
+    fn from_iter<I: IntoIterator<Item = (&'a str, String)>>(iter: I) -> Self {
+        Overrides(
+            iter.into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+}
+impl Display for Overrides {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .join(",")
+        )
+    }
+}
+
+#[derive(Default)]
+struct Active {
+    values: BTreeMap<String, String>,
+    used: BTreeSet<String>,
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Active> = RefCell::new(Active::default());
+}
+
+/**
+Puts back whatever was active before, even if the solution panics
+*/
+struct Restore(Option<Active>);
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take().unwrap_or_default();
+        ACTIVE.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+/**
+Runs `f` with `overrides` applied to every `Param::get` on this thread, but not on threads `f`
+spawns. Fails if an override was never read, since that's almost always a typo in the parameter
+name.
+*/
+pub fn with_overrides<R>(overrides: &Overrides, f: impl FnOnce() -> R) -> Result<R, String> {
+    let previous = ACTIVE.with(|active| {
+        active.replace(Active {
+            values: overrides.0.clone(),
+            used: BTreeSet::new(),
+        })
+    });
+    let restore = Restore(Some(previous));
+    let result = f();
+    let unused = ACTIVE.with(|active| {
+        let active = active.borrow();
+        overrides
+            .0
+            .keys()
+            .filter(|name| !active.used.contains(*name))
+            .cloned()
+            .collect_vec()
+    });
+    drop(restore);
+    if unused.is_empty() {
+        Ok(result)
+    } else {
+        Err(format!("Unknown parameters: {}", unused.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: Param<usize> = Param::new("size", 71);
+
+    #[test]
+    fn parses_and_displays() {
+        let overrides = "size=7,bytes=12".parse::<Overrides>().unwrap();
+        assert_eq!(overrides.to_string(), "bytes=12,size=7");
+        assert_eq!("".parse::<Overrides>(), Ok(Overrides::default()));
+        assert!("size".parse::<Overrides>().is_err());
+        assert!("=7".parse::<Overrides>().is_err());
+    }
+
+    #[test]
+    fn overrides_only_apply_inside() {
+        assert_eq!(SIZE.get(), 71);
+        let overrides = [("size", "7".to_string())].into_iter().collect();
+        assert_eq!(with_overrides(&overrides, || SIZE.get()), Ok(7));
+        assert_eq!(SIZE.get(), 71);
+        assert_eq!(with_overrides(&Overrides::default(), || SIZE.get()), Ok(71));
+    }
+
+    #[test]
+    fn spawned_threads_see_defaults() {
+        let overrides = "size=7".parse().unwrap();
+        let sizes = with_overrides(&overrides, || {
+            let size = SIZE.get();
+            (size, std::thread::spawn(|| SIZE.get()).join().unwrap())
+        });
+        assert_eq!(sizes, Ok((7, 71)));
+    }
+
+    #[test]
+    fn rejects_unused_overrides() {
+        let overrides = "size=7,sise=8".parse().unwrap();
+        assert_eq!(
+            with_overrides(&overrides, || SIZE.get()),
+            Err("Unknown parameters: sise".to_string())
+        );
+    }
+
+    #[test]
+    fn restores_after_a_panic() {
+        let overrides = "size=7".parse().unwrap();
+        let result = std::panic::catch_unwind(|| {
+            with_overrides(&overrides, || -> usize { panic!("{}", SIZE.get()) })
+        });
+        assert!(result.is_err());
+        assert_eq!(SIZE.get(), 71);
+    }
+}
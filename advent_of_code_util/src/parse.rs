@@ -1,46 +1,186 @@
 use std::{
+    convert::Infallible,
+    fmt::Display,
     fs::File,
     io::{self, BufRead},
-    path::Path,
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
 use itertools::Itertools;
 
-pub fn read_lines<P>(filename: P) -> Vec<String>
+use crate::grid::Grid;
+
+/**
+Where and why an input couldn't be read. `line` and `column` count from 1, and are 0 when the file as
+a whole is the problem, like when it can't be opened.
+*/
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+impl ParseError {
+    fn in_file(file: &Path, message: impl Display) -> Self {
+        ParseError {
+            file: file.to_path_buf(),
+            line: 0,
+            column: 0,
+            text: String::new(),
+            message: message.to_string(),
+        }
+    }
+
+    /**
+    An error in `text`, starting at the byte `offset` into line number `line_index` (from 0)
+    */
+    fn at(
+        file: &Path,
+        line_index: usize,
+        line: &str,
+        offset: usize,
+        text: &str,
+        message: impl Display,
+    ) -> Self {
+        ParseError {
+            file: file.to_path_buf(),
+            line: line_index + 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}: {}", self.file.display(), self.message)
+        } else {
+            write!(
+                f,
+                "{}:{}:{}: {} at \"{}\"",
+                self.file.display(),
+                self.line,
+                self.column,
+                self.message,
+                self.text
+            )
+        }
+    }
+}
+impl std::error::Error for ParseError {}
+
+fn panic_on_error<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_read_lines<P>(filename: P) -> Result<Vec<String>, ParseError>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename).unwrap();
+    let path = filename.as_ref();
+    let file = File::open(path).map_err(|err| ParseError::in_file(path, err))?;
     io::BufReader::new(file)
         .lines()
-        .map(|line| line.unwrap())
+        .enumerate()
+        .map(|(index, line)| {
+            line.map_err(|err| ParseError {
+                line: index + 1,
+                column: 1,
+                ..ParseError::in_file(path, err)
+            })
+        })
         .collect()
 }
 
+pub fn read_lines<P>(filename: P) -> Vec<String>
+where
+    P: AsRef<Path>,
+{
+    panic_on_error(try_read_lines(filename))
+}
+
+fn parse_lines<T, P>(filename: P, describe: impl Fn(T::Err) -> String) -> Result<Vec<T>, ParseError>
+where
+    P: AsRef<Path>,
+    T: FromStr,
+{
+    let path = filename.as_ref();
+    try_read_lines(path)?
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            T::from_str(line)
+                .map_err(|err| ParseError::at(path, index, line, 0, line, describe(err)))
+        })
+        .collect()
+}
+
+/**
+Parses every line with `T::from_str`, failing on the first line it rejects
+*/
+pub fn try_read_parsed_lines<T, P>(filename: P) -> Result<Vec<T>, ParseError>
+where
+    P: AsRef<Path>,
+    T: FromStr,
+    <T as std::str::FromStr>::Err: Display,
+{
+    parse_lines(filename, |err: T::Err| err.to_string())
+}
+
 pub fn read_parsed_lines<T, P>(filename: P) -> Vec<T>
 where
     P: AsRef<Path>,
     T: FromStr,
     <T as std::str::FromStr>::Err: std::fmt::Debug,
 {
-    let file = File::open(filename).unwrap();
-    io::BufReader::new(file)
-        .lines()
-        .map(|line| line.unwrap())
-        .map(|line| T::from_str(&line).unwrap())
-        .collect()
+    panic_on_error(parse_lines(filename, |err: T::Err| format!("{:?}", err)))
 }
 
-pub fn read_lines_of_chars<P>(filename: P) -> Vec<Vec<char>>
+pub fn try_read_lines_of_chars<P>(filename: P) -> Result<Vec<Vec<char>>, ParseError>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename).unwrap();
-    io::BufReader::new(file)
-        .lines()
-        .map(|line| line.unwrap())
+    Ok(try_read_lines(filename)?
+        .into_iter()
         .map(|line| line.chars().collect_vec())
+        .collect())
+}
+
+pub fn read_lines_of_chars<P>(filename: P) -> Vec<Vec<char>>
+where
+    P: AsRef<Path>,
+{
+    panic_on_error(try_read_lines_of_chars(filename))
+}
+
+pub fn try_read_grid_of_digits<P>(filename: P) -> Result<Vec<Vec<usize>>, ParseError>
+where
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref();
+    try_read_lines(path)?
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            line.char_indices()
+                .map(|(offset, c)| {
+                    c.to_digit(10).map(|digit| digit as usize).ok_or_else(|| {
+                        ParseError::at(
+                            path,
+                            index,
+                            line,
+                            offset,
+                            &c.to_string(),
+                            "Expected a digit",
+                        )
+                    })
+                })
+                .collect()
+        })
         .collect()
 }
 
@@ -48,39 +188,102 @@ pub fn read_grid_of_digits<P>(filename: P) -> Vec<Vec<usize>>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename).unwrap();
-    io::BufReader::new(file)
-        .lines()
-        .map(|line| line.unwrap())
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as usize)
-                .collect_vec()
+    panic_on_error(try_read_grid_of_digits(filename))
+}
+
+fn into_grid<T>(path: &Path, rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError> {
+    let width = rows.first().map_or(0, Vec::len);
+    if let Some((index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+        return Err(ParseError {
+            line: index + 1,
+            column: width.min(row.len()) + 1,
+            ..ParseError::in_file(path, format!("Expected {} cells, got {}", width, row.len()))
+        });
+    }
+    Ok(Grid::try_from(rows).expect("rows are all the same length"))
+}
+
+/**
+The file as a grid of characters, failing if its lines aren't all the same length
+*/
+pub fn try_read_grid<P>(filename: P) -> Result<Grid<char>, ParseError>
+where
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref();
+    into_grid(path, try_read_lines_of_chars(path)?)
+}
+
+pub fn read_grid<P>(filename: P) -> Grid<char>
+where
+    P: AsRef<Path>,
+{
+    panic_on_error(try_read_grid(filename))
+}
+
+pub fn try_read_digit_grid<P>(filename: P) -> Result<Grid<usize>, ParseError>
+where
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref();
+    into_grid(path, try_read_grid_of_digits(path)?)
+}
+
+pub fn read_digit_grid<P>(filename: P) -> Grid<usize>
+where
+    P: AsRef<Path>,
+{
+    panic_on_error(try_read_digit_grid(filename))
+}
+
+/**
+Splits every line on `separator` and transforms each piece, failing on the first piece it rejects
+*/
+pub fn try_read_list_of_lists<P, F, T, E>(
+    filename: P,
+    separator: &str,
+    mut transform: F,
+) -> Result<Vec<Vec<T>>, ParseError>
+where
+    P: AsRef<Path>,
+    F: FnMut(&str) -> Result<T, E>,
+    E: Display,
+{
+    let path = filename.as_ref();
+    try_read_lines(path)?
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let mut offset = 0;
+            line.split(separator)
+                .map(|item| {
+                    let item_offset = offset;
+                    offset += item.len() + separator.len();
+                    transform(item)
+                        .map_err(|err| ParseError::at(path, index, line, item_offset, item, err))
+                })
+                .collect()
         })
         .collect()
 }
 
-pub fn read_list_of_lists<P, F, T>(filename: P, separator: &str, transform: F) -> Vec<Vec<T>>
+pub fn read_list_of_lists<P, F, T>(filename: P, separator: &str, mut transform: F) -> Vec<Vec<T>>
 where
     P: AsRef<Path>,
     F: FnMut(&str) -> T + Copy,
 {
-    let file = File::open(filename).unwrap();
-    io::BufReader::new(file)
-        .lines()
-        .map(|line| line.unwrap())
-        .map(|line| line.split(separator).map(transform).collect_vec())
-        .collect()
+    panic_on_error(try_read_list_of_lists(filename, separator, |item| {
+        Ok::<_, Infallible>(transform(item))
+    }))
 }
 
-pub fn read_blocks<P>(filename: P) -> Vec<Vec<String>>
+pub fn try_read_blocks<P>(filename: P) -> Result<Vec<Vec<String>>, ParseError>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename).unwrap();
     let mut blocks = vec![];
     let mut latest_block = vec![];
-    for line in io::BufReader::new(file).lines().map(|line| line.unwrap()) {
+    for line in try_read_lines(filename)? {
         if line.is_empty() {
             blocks.push(latest_block);
             latest_block = vec![];
@@ -91,7 +294,14 @@ where
     if !latest_block.is_empty() {
         blocks.push(latest_block);
     }
-    blocks
+    Ok(blocks)
+}
+
+pub fn read_blocks<P>(filename: P) -> Vec<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    panic_on_error(try_read_blocks(filename))
 }
 
 pub fn split_block_on_whitespace(block: Vec<String>) -> Vec<String> {
@@ -128,4 +338,67 @@ mod tests {
             ]
         );
     }
+
+    fn write_input(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc_parse_{}_{}", name, std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let digits = write_input("digits", "123\n4x6\n");
+        let lists = write_input("lists", "1 2 3\n4 éh 6\n");
+        let coordinates = write_input("coordinates", "1,2\n3;4\n");
+        let grid = try_read_grid_of_digits(&digits);
+        let list = try_read_list_of_lists(&lists, " ", |item| item.parse::<usize>());
+        let parsed = try_read_parsed_lines::<crate::Coordinate, _>(&coordinates);
+        let ok = try_read_list_of_lists(&digits, "x", |item| Ok::<_, String>(item.len()));
+        for path in [&digits, &lists, &coordinates] {
+            std::fs::remove_file(path).unwrap();
+        }
+
+        let grid = grid.unwrap_err();
+        assert_eq!((grid.line, grid.column, grid.text.as_str()), (2, 2, "x"));
+        assert_eq!(
+            grid.to_string(),
+            format!("{}:2:2: Expected a digit at \"x\"", digits.display())
+        );
+        let list = list.unwrap_err();
+        assert_eq!((list.line, list.column, list.text.as_str()), (2, 3, "éh"));
+        assert_eq!(list.message, "invalid digit found in string");
+        let parsed = parsed.unwrap_err();
+        assert_eq!(
+            (parsed.line, parsed.column, parsed.text.as_str()),
+            (2, 1, "3;4")
+        );
+        assert_eq!(parsed.message, "Expected x,y, got \"3;4\"");
+        assert_eq!(ok, Ok(vec![vec![3], vec![1, 1]]));
+        assert_eq!(
+            "-1,a".parse::<crate::icoordinate::ICoordinate>(),
+            Err("Invalid number \"a\" in \"-1,a\"".to_string())
+        );
+    }
+
+    #[test]
+    fn reads_grids() {
+        let square = write_input("square", "12\n34\n");
+        let ragged = write_input("ragged", "ab\nc\n");
+        let digits = read_digit_grid(&square);
+        let chars = try_read_grid(&ragged);
+        std::fs::remove_file(&square).unwrap();
+        std::fs::remove_file(&ragged).unwrap();
+
+        assert_eq!(digits[crate::Coordinate { x: 0, y: 1 }], 3);
+        let chars = chars.unwrap_err();
+        assert_eq!((chars.line, chars.column), (2, 2));
+        assert_eq!(chars.message, "Expected 2 cells, got 1");
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let err = try_read_blocks("/definitely/not/an/input").unwrap_err();
+        assert_eq!((err.line, err.column), (0, 0));
+        assert!(err.to_string().starts_with("/definitely/not/an/input: "));
+    }
 }
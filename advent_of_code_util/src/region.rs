@@ -0,0 +1,286 @@
+use std::collections::BTreeSet;
+
+use crate::{grid::Grid, icoordinate::ICoordinate, Coordinate, Direction};
+
+/**
+A connected group of cells in a grid, where every cell can reach every other by steps up, down, left
+or right
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub label: usize,
+    cells: BTreeSet<Coordinate>,
+}
+impl Region {
+    /**
+    The cells, sorted by `x` then `y`
+    */
+    pub fn cells(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        self.cells.iter().copied()
+    }
+
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        self.cells.contains(&coordinate)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn contains_signed(&self, coordinate: ICoordinate) -> bool {
+        Coordinate::try_from(coordinate).is_ok_and(|coordinate| self.contains(coordinate))
+    }
+
+    fn signed_cells(&self) -> impl Iterator<Item = ICoordinate> + '_ {
+        self.cells()
+            .map(|cell| ICoordinate::try_from(cell).unwrap())
+    }
+
+    /**
+    The number of cell edges with something outside the region on the other side
+    */
+    pub fn perimeter(&self) -> usize {
+        self.signed_cells()
+            .flat_map(ICoordinate::neighbours)
+            .filter(|&neighbour| !self.contains_signed(neighbour))
+            .count()
+    }
+
+    /**
+    The number of straight sides around the region, holes included. A polygon has as many sides as
+    corners, so this counts corners: outside ones where both neighbours either side of a corner are
+    missing, and inside ones where both are present but the diagonal between them isn't.
+    */
+    pub fn sides(&self) -> usize {
+        self.signed_cells()
+            .map(|cell| {
+                Direction::iter()
+                    .filter(|&direction| {
+                        let (a, b) = (cell + direction, cell + direction.turn_right());
+                        let diagonal = cell + direction.unit() + direction.turn_right().unit();
+                        match (self.contains_signed(a), self.contains_signed(b)) {
+                            (false, false) => true,
+                            (true, true) => !self.contains_signed(diagonal),
+                            _ => false,
+                        }
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /**
+    The smallest and largest corners of the box around the region
+    */
+    pub fn bounds(&self) -> (Coordinate, Coordinate) {
+        let (xs, ys): (Vec<_>, Vec<_>) = self.cells().map(|c| (c.x, c.y)).unzip();
+        (
+            Coordinate {
+                x: *xs.iter().min().unwrap(),
+                y: *ys.iter().min().unwrap(),
+            },
+            Coordinate {
+                x: *xs.iter().max().unwrap(),
+                y: *ys.iter().max().unwrap(),
+            },
+        )
+    }
+
+    /**
+    The groups of cells outside the region that it completely surrounds, so they can't reach the
+    edge of its bounding box without crossing it
+    */
+    pub fn holes(&self) -> Vec<BTreeSet<Coordinate>> {
+        let (min, max) = self.bounds();
+        let inside_box =
+            |c: &Coordinate| (min.x..=max.x).contains(&c.x) && (min.y..=max.y).contains(&c.y);
+        let gaps = (min.y..=max.y)
+            .flat_map(|y| (min.x..=max.x).map(move |x| Coordinate { x, y }))
+            .filter(|&c| !self.contains(c))
+            .collect::<BTreeSet<_>>();
+        connected_components(gaps.iter().copied(), |&c| {
+            Direction::iter()
+                .filter_map(move |direction| c.step(direction))
+                .filter(|neighbour| gaps.contains(neighbour))
+        })
+        .into_iter()
+        .filter(|gap| {
+            gap.iter().all(|&c| {
+                Direction::iter().all(|direction| c.step(direction).is_some_and(|n| inside_box(&n)))
+            })
+        })
+        .collect()
+    }
+}
+
+/**
+Every cell of a grid sorted into regions, with a label grid to look up which region a cell is in
+*/
+#[derive(Debug, Clone)]
+pub struct Regions {
+    pub labels: Grid<Option<usize>>,
+    pub regions: Vec<Region>,
+}
+impl Regions {
+    pub fn label_of(&self, coordinate: Coordinate) -> Option<usize> {
+        self.labels.get(coordinate).copied().flatten()
+    }
+
+    pub fn region_of(&self, coordinate: Coordinate) -> Option<&Region> {
+        self.label_of(coordinate).map(|label| &self.regions[label])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+}
+
+/**
+The cells reachable from `start` by stepping between neighbours for which `joined` holds
+*/
+pub fn flood_fill<T>(
+    grid: &Grid<T>,
+    start: Coordinate,
+    joined: impl Fn(&T, &T) -> bool,
+) -> BTreeSet<Coordinate> {
+    let mut filled = BTreeSet::from([start]);
+    let mut queue = vec![start];
+    while let Some(coordinate) = queue.pop() {
+        for neighbour in grid.neighbours(coordinate) {
+            if joined(&grid[coordinate], &grid[neighbour]) && filled.insert(neighbour) {
+                queue.push(neighbour);
+            }
+        }
+    }
+    filled
+}
+
+/**
+Splits the whole grid into regions of neighbouring cells for which `joined` holds, like plots of the
+same plant with `|a, b| a == b`
+*/
+pub fn regions<T>(grid: &Grid<T>, joined: impl Fn(&T, &T) -> bool) -> Regions {
+    label(grid, |_| true, joined)
+}
+
+/**
+The regions made by the cells matching `include`, leaving the rest unlabelled
+*/
+pub fn regions_where<T>(grid: &Grid<T>, include: impl Fn(&T) -> bool) -> Regions {
+    label(grid, &include, |a, b| include(a) && include(b))
+}
+
+fn label<T>(
+    grid: &Grid<T>,
+    include: impl Fn(&T) -> bool,
+    joined: impl Fn(&T, &T) -> bool,
+) -> Regions {
+    let mut labels = Grid::new(grid.width(), grid.height(), None);
+    let mut regions = vec![];
+    for coordinate in grid.coordinates() {
+        if labels[coordinate].is_some() || !include(&grid[coordinate]) {
+            continue;
+        }
+        let cells = flood_fill(grid, coordinate, &joined);
+        for &cell in &cells {
+            labels[cell] = Some(regions.len());
+        }
+        regions.push(Region {
+            label: regions.len(),
+            cells,
+        });
+    }
+    Regions { labels, regions }
+}
+
+/**
+Groups the nodes of a graph given as a neighbour lookup, such as an adjacency map, into connected
+components
+*/
+pub fn connected_components<N: Ord + Clone, I: IntoIterator<Item = N>>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Vec<BTreeSet<N>> {
+    let mut seen = BTreeSet::new();
+    let mut components = vec![];
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let mut component = BTreeSet::from([node.clone()]);
+        let mut queue = vec![node];
+        while let Some(item) = queue.pop() {
+            for neighbour in neighbours(&item) {
+                if component.insert(neighbour.clone()) {
+                    queue.push(neighbour);
+                }
+            }
+        }
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn garden(rows: &[&str]) -> Grid<char> {
+        Grid::try_from(
+            rows.iter()
+                .map(|row| row.chars().collect())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn measures_plots() {
+        let grid = garden(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let regions = regions(&grid, |a, b| a == b);
+        let measures = regions
+            .iter()
+            .map(|region| (region.area(), region.perimeter(), region.sides()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            measures,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(regions.label_of(Coordinate { x: 3, y: 3 }), Some(2));
+        assert_eq!(
+            regions.regions[2].bounds(),
+            (Coordinate { x: 2, y: 1 }, Coordinate { x: 3, y: 3 })
+        );
+    }
+
+    #[test]
+    fn finds_holes() {
+        let grid = garden(&["OOOOO", "OXOXO", "OOOOO", "OXOXO", "OOOOO"]);
+        let regions = regions(&grid, |a, b| a == b);
+        let outer = regions.region_of(Coordinate { x: 0, y: 0 }).unwrap();
+        assert_eq!(outer.holes().len(), 4);
+        assert_eq!(outer.sides(), 20);
+        assert!(regions.regions[1].holes().is_empty());
+
+        let lagoon = regions_where(&garden(&["###.", "#.#.", "###."]), |&c| c == '#');
+        assert_eq!(lagoon.regions.len(), 1);
+        assert_eq!(lagoon.label_of(Coordinate { x: 3, y: 0 }), None);
+        assert_eq!(
+            lagoon.regions[0].holes(),
+            vec![BTreeSet::from([Coordinate { x: 1, y: 1 }])]
+        );
+    }
+
+    #[test]
+    fn splits_graphs() {
+        let edges = BTreeMap::from([(1, vec![2]), (2, vec![1, 3]), (3, vec![2]), (4, vec![])]);
+        let components = connected_components(edges.keys().copied(), |node| edges[node].clone());
+        assert_eq!(
+            components,
+            vec![BTreeSet::from([1, 2, 3]), BTreeSet::from([4])]
+        );
+    }
+}
@@ -0,0 +1,686 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{grid::Grid, Coordinate, Point};
+
+/**
+How a search reached a state: its cheapest known cost, and the state it was reached from at that
+cost, which is `None` for a start
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Reached<S> {
+    pub cost: usize,
+    pub previous: Option<S>,
+}
+
+/**
+Somewhere to keep how each state was reached. Costs and the way back are stored together, so
+storage that avoids hashing avoids it for paths too.
+*/
+pub trait Distances<S> {
+    fn get(&self, state: &S) -> Option<&Reached<S>>;
+
+    fn set(&mut self, state: S, reached: Reached<S>);
+}
+impl<S: Hash + Eq> Distances<S> for HashMap<S, Reached<S>> {
+    fn get(&self, state: &S) -> Option<&Reached<S>> {
+        HashMap::get(self, state)
+    }
+
+    fn set(&mut self, state: S, reached: Reached<S>) {
+        self.insert(state, reached);
+    }
+}
+
+/**
+A grid with a cell per coordinate, which is also a handy distance map to keep afterwards
+*/
+impl Distances<Coordinate> for Grid<Option<Reached<Coordinate>>> {
+    fn get(&self, state: &Coordinate) -> Option<&Reached<Coordinate>> {
+        self[*state].as_ref()
+    }
+
+    fn set(&mut self, state: Coordinate, reached: Reached<Coordinate>) {
+        self[state] = Some(reached);
+    }
+}
+
+/**
+Storage in a `Vec`, for when every state maps to a small index, like the cells of a grid. That's a
+lot quicker than hashing.
+*/
+#[derive(Debug, Clone)]
+pub struct Dense<S, F> {
+    reached: Vec<Option<Reached<S>>>,
+    index: F,
+}
+impl<S: Clone, F> Dense<S, F> {
+    /**
+    Room for states `0..size` as numbered by `index`
+    */
+    pub fn new(size: usize, index: F) -> Self {
+        Dense {
+            reached: vec![None; size],
+            index,
+        }
+    }
+}
+impl<S, F: Fn(&S) -> usize> Distances<S> for Dense<S, F> {
+    fn get(&self, state: &S) -> Option<&Reached<S>> {
+        self.reached[(self.index)(state)].as_ref()
+    }
+
+    fn set(&mut self, state: S, reached: Reached<S>) {
+        self.reached[(self.index)(&state)] = Some(reached);
+    }
+}
+
+/**
+What a search found: how it reached every state it got to, and the goal if it reached one.
+
+Dijkstra and A* stop as soon as they take a goal off the queue, so only the states looked at before
+then are sure to have their cheapest cost. Anything still queued has the best cost found so far,
+which might be beaten. Search with `|_| false` for final costs everywhere. Breadth first search
+doesn't have this problem, since the first way it finds to a state is always the shortest.
+*/
+#[derive(Debug, Clone)]
+pub struct Search<S, D = HashMap<S, Reached<S>>> {
+    distances: D,
+    goal: Option<S>,
+    expanded: usize,
+}
+impl<S: Clone + Eq + Hash, D: Distances<S>> Search<S, D> {
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /**
+    The cost of reaching `state`, which is only sure to be the cheapest for states looked at before
+    stopping, see `Search`
+    */
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).map(|reached| reached.cost)
+    }
+
+    /**
+    The way from a start to `state` at the cost it was reached, including both ends
+    */
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut reached = self.distances.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = &reached.previous {
+            path.push(previous.clone());
+            reached = self.distances.get(previous)?;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /**
+    How many states had their successors looked at, which is how much work the search did
+    */
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    pub fn distances(&self) -> &D {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> D {
+        self.distances
+    }
+}
+
+/**
+Dijkstra's algorithm from `starts`, stopping at the first state `is_goal` accepts. `successors`
+gives each state's neighbours along with the cost of moving there. Use `|_| false` to cost every
+reachable state.
+*/
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    dijkstra_with(HashMap::new(), starts, successors, is_goal)
+}
+
+/**
+Like `dijkstra`, but keeping costs in `distances`, such as a `Dense`
+*/
+pub fn dijkstra_with<S, I, D>(
+    distances: D,
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, D>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    D: Distances<S>,
+{
+    best_first(distances, starts, successors, is_goal, |_| 0)
+}
+
+/**
+A* search, which is Dijkstra's algorithm looking at the states `heuristic` thinks are closest to a
+goal first. The answer is only guaranteed cheapest if `heuristic` never overestimates the remaining
+cost, like `manhattan` to the goal on a grid where every step costs at least one.
+*/
+pub fn a_star<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> usize,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    a_star_with(HashMap::new(), starts, successors, is_goal, heuristic)
+}
+
+/**
+Like `a_star`, but keeping costs in `distances`, such as a `Dense`
+*/
+pub fn a_star_with<S, I, D>(
+    distances: D,
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> usize,
+) -> Search<S, D>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    D: Distances<S>,
+{
+    best_first(distances, starts, successors, is_goal, heuristic)
+}
+
+/**
+The number of orthogonal steps between two points, which never overestimates on a grid without
+diagonal moves
+*/
+pub fn manhattan<P: Point>(from: &P, to: &P) -> usize {
+    let ((x0, y0), (x1, y1)) = (from.components(), to.components());
+    x0.abs_diff(x1) + y0.abs_diff(y1)
+}
+
+/**
+The number of steps between two points when diagonal moves are allowed too
+*/
+pub fn chebyshev<P: Point>(from: &P, to: &P) -> usize {
+    let ((x0, y0), (x1, y1)) = (from.components(), to.components());
+    x0.abs_diff(x1).max(y0.abs_diff(y1))
+}
+
+fn best_first<S, I, D>(
+    mut distances: D,
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> usize,
+) -> Search<S, D>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    D: Distances<S>,
+{
+    // The heap only holds priorities and indexes into `queued`, so states don't need to be `Ord`.
+    // Ties go to whichever looks closer to the goal, then to the one queued first.
+    let mut queued = vec![];
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        distances.set(
+            start.clone(),
+            Reached {
+                cost: 0,
+                previous: None,
+            },
+        );
+        let estimate = heuristic(&start);
+        heap.push(Reverse((estimate, estimate, queued.len())));
+        queued.push((start, 0));
+    }
+
+    let mut goal = None;
+    let mut expanded = 0;
+    while let Some(Reverse((_, _, index))) = heap.pop() {
+        let (state, cost) = queued[index].clone();
+        if distances.get(&state).is_some_and(|best| cost > best.cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            goal = Some(state);
+            break;
+        }
+        expanded += 1;
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if distances
+                .get(&next)
+                .is_none_or(|best| next_cost < best.cost)
+            {
+                distances.set(
+                    next.clone(),
+                    Reached {
+                        cost: next_cost,
+                        previous: Some(state.clone()),
+                    },
+                );
+                let estimate = heuristic(&next);
+                heap.push(Reverse((next_cost + estimate, estimate, queued.len())));
+                queued.push((next, next_cost));
+            }
+        }
+    }
+
+    Search {
+        distances,
+        goal,
+        expanded,
+    }
+}
+
+/**
+Breadth first search from `starts`, for when every step costs one. Stops at the first state
+`is_goal` accepts, or with `|_| false` gives the number of steps to every reachable state.
+*/
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_with(HashMap::new(), starts, successors, is_goal)
+}
+
+/**
+Like `bfs`, but keeping step counts in `distances`, such as a `Dense`
+*/
+pub fn bfs_with<S, I, D>(
+    mut distances: D,
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, D>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    D: Distances<S>,
+{
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if distances.get(&start).is_none() {
+            distances.set(
+                start.clone(),
+                Reached {
+                    cost: 0,
+                    previous: None,
+                },
+            );
+            queue.push_back((start, 0));
+        }
+    }
+
+    let mut goal = None;
+    let mut expanded = 0;
+    while let Some((state, steps)) = queue.pop_front() {
+        if is_goal(&state) {
+            goal = Some(state);
+            break;
+        }
+        expanded += 1;
+        for next in successors(&state) {
+            if distances.get(&next).is_none() {
+                distances.set(
+                    next.clone(),
+                    Reached {
+                        cost: steps + 1,
+                        previous: Some(state.clone()),
+                    },
+                );
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    Search {
+        distances,
+        goal,
+        expanded,
+    }
+}
+
+/**
+`bfs` over the cells of `grid` that are `passable`, keeping the step counts in a grid the same size
+*/
+pub fn bfs_grid<T>(
+    grid: &Grid<T>,
+    starts: impl IntoIterator<Item = Coordinate>,
+    passable: impl Fn(&T) -> bool,
+    is_goal: impl FnMut(&Coordinate) -> bool,
+) -> Search<Coordinate, Grid<Option<Reached<Coordinate>>>> {
+    let passable = &passable;
+    bfs_with(
+        Grid::new(grid.width(), grid.height(), None),
+        starts,
+        move |&coordinate| {
+            grid.neighbours(coordinate)
+                .filter(move |&neighbour| passable(&grid[neighbour]))
+        },
+        is_goal,
+    )
+}
+
+/**
+Every cheapest way to the goals, kept as a graph where each state points back at all the states it
+can be reached from at its best cost
+*/
+#[derive(Debug, Clone)]
+pub struct AllPaths<S> {
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+impl<S: Clone + Eq + Hash> AllPaths<S> {
+    /**
+    The goal states reached at the cheapest cost, which can be more than one
+    */
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goals.first().and_then(|goal| self.cost(goal))
+    }
+
+    /**
+    The cost of reaching `state`. The search stops once everything as cheap as the goals has been
+    looked at, so states dearer than that might only have the best cost found so far.
+    */
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /**
+    The states one step before `state` on a cheapest path to it
+    */
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /**
+    How many different cheapest paths there are to the goals, without listing them
+    */
+    pub fn count_paths(&self) -> usize {
+        let mut counts = HashMap::new();
+        self.goals
+            .iter()
+            .map(|goal| self.count_paths_to(goal, &mut counts))
+            .sum()
+    }
+
+    fn count_paths_to(&self, state: &S, counts: &mut HashMap<S, usize>) -> usize {
+        if let Some(&count) = counts.get(state) {
+            return count;
+        }
+        let predecessors = self.predecessors(state);
+        let count = if predecessors.is_empty() {
+            1
+        } else {
+            predecessors
+                .iter()
+                .map(|previous| self.count_paths_to(previous, counts))
+                .sum()
+        };
+        counts.insert(state.clone(), count);
+        count
+    }
+
+    /**
+    Every cheapest path to the goals, from start to goal. There can be a lot of them, so see
+    `count_paths` first.
+    */
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = vec![];
+        for goal in &self.goals {
+            let mut stack = vec![vec![goal.clone()]];
+            while let Some(path) = stack.pop() {
+                let predecessors = self.predecessors(path.last().unwrap());
+                if predecessors.is_empty() {
+                    paths.push(path.into_iter().rev().collect());
+                    continue;
+                }
+                for previous in predecessors {
+                    let mut longer = path.clone();
+                    longer.push(previous.clone());
+                    stack.push(longer);
+                }
+            }
+        }
+        paths
+    }
+
+    /**
+    Every state that lies on at least one cheapest path to a goal
+    */
+    pub fn states_on_paths(&self) -> HashSet<S> {
+        let mut on_paths = self.goals.iter().cloned().collect::<HashSet<_>>();
+        let mut queue = self.goals.clone();
+        while let Some(state) = queue.pop() {
+            for previous in self.predecessors(&state) {
+                if on_paths.insert(previous.clone()) {
+                    queue.push(previous.clone());
+                }
+            }
+        }
+        on_paths
+    }
+}
+
+/**
+Dijkstra's algorithm that keeps every cheapest way of reaching each state rather than just one. It
+carries on until everything as cheap as the first goal found has been looked at, so it finds all the
+equally good goals too.
+*/
+pub fn dijkstra_all<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> AllPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut queued = vec![];
+    let mut heap = BinaryHeap::new();
+    let mut distances = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    for start in starts {
+        distances.insert(start.clone(), 0);
+        heap.push(Reverse((0, queued.len())));
+        queued.push(start);
+    }
+
+    let mut goals = vec![];
+    let mut goal_cost = None;
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        let state = queued[index].clone();
+        if distances.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match distances.get(&next) {
+                Some(&best) if next_cost > best => {}
+                Some(&best) if next_cost == best => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    distances.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    heap.push(Reverse((next_cost, queued.len())));
+                    queued.push(next);
+                }
+            }
+        }
+    }
+
+    AllPaths {
+        distances,
+        predecessors,
+        goals,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{icoordinate::ICoordinate, Coordinate, Direction};
+
+    fn graph(node: &char) -> Vec<(char, usize)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 2)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('b', 3), ('d', 8)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        let search = dijkstra(['a'], graph, |&node| node == 'd');
+        assert_eq!(search.goal(), Some(&'d'));
+        assert_eq!(search.goal_cost(), Some(6));
+        assert_eq!(search.path(), Some(vec!['a', 'c', 'b', 'd']));
+        assert_eq!(search.path_to(&'c'), Some(vec!['a', 'c']));
+        assert_eq!(search.path_to(&'e'), None);
+
+        let unreachable = dijkstra(['b'], graph, |&node| node == 'a');
+        assert_eq!(unreachable.goal(), None);
+        assert_eq!(unreachable.cost(&'d'), Some(1));
+    }
+
+    #[test]
+    fn a_star_expands_less() {
+        let (size, wall) = (20, 10);
+        let goal = Coordinate {
+            x: size - 1,
+            y: size - 1,
+        };
+        let open = |c: &Coordinate| c.x < size && c.y < size && !(c.x == wall && c.y > 0);
+        let successors = |&c: &Coordinate| {
+            Direction::iter()
+                .filter_map(move |direction| c.step(direction))
+                .filter(open)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let start = [Coordinate { x: 0, y: size - 1 }];
+        let plain = dijkstra(start, successors, |&c| c == goal);
+        let guided = a_star(start, successors, |&c| c == goal, |c| manhattan(c, &goal));
+        assert_eq!(plain.goal_cost(), Some(57));
+        assert_eq!(guided.goal_cost(), plain.goal_cost());
+        assert_eq!(guided.path().unwrap().len(), 58);
+        assert!(guided.expanded() < plain.expanded());
+        assert_eq!(
+            chebyshev(&ICoordinate { x: -3, y: 1 }, &ICoordinate { x: 2, y: 0 }),
+            5
+        );
+    }
+
+    #[test]
+    fn maps_distances_breadth_first() {
+        let maze = Grid::try_from(
+            ["S.#.", "..#.", "#...", "E#.."]
+                .iter()
+                .map(|row| row.chars().collect())
+                .collect::<Vec<Vec<char>>>(),
+        )
+        .unwrap();
+        let start = maze.position_of(&'S').unwrap();
+        let everywhere = bfs_grid(&maze, [start], |&c| c != '#', |_| false);
+        assert_eq!(everywhere.goal(), None);
+        assert_eq!(
+            everywhere
+                .distances()
+                .map(|reached| reached.map_or('#', |reached| char::from(b'0' + reached.cost as u8)))
+                .to_string(),
+            "01#7\n12#6\n#345\n##56"
+        );
+
+        let corner = Coordinate { x: 3, y: 0 };
+        let to_corner = bfs_grid(&maze, [start], |&c| c != '#', |&c| c == corner);
+        assert_eq!(to_corner.goal_cost(), Some(7));
+        assert_eq!(to_corner.path().unwrap().len(), 8);
+        assert!(to_corner.expanded() < everywhere.expanded());
+
+        let bits = bfs([0u8], |&n| [n ^ 1, n ^ 4, n ^ 8], |&n| n == 13);
+        assert_eq!(bits.goal_cost(), Some(3));
+        assert_eq!(bits.distances().len(), 8);
+    }
+
+    #[test]
+    fn keeps_every_cheapest_path() {
+        // Two diamonds in a row, a dearer shortcut, and a second goal as cheap as the first
+        let graph = |node: &char| match node {
+            's' => vec![('a', 1), ('b', 1), ('m', 5)],
+            'a' | 'b' => vec![('m', 1)],
+            'm' => vec![('c', 2), ('d', 2), ('y', 4)],
+            'c' | 'd' => vec![('x', 2)],
+            _ => vec![],
+        };
+        let all = dijkstra_all(['s'], graph, |node| "xy".contains(*node));
+        assert_eq!(all.goal_cost(), Some(6));
+        assert_eq!(all.goals().len(), 2);
+        assert_eq!(all.count_paths(), 6);
+        let mut paths = all.paths();
+        paths.sort();
+        assert_eq!(paths.len(), 6);
+        assert_eq!(paths[0], vec!['s', 'a', 'm', 'c', 'x']);
+        let on_paths = all.states_on_paths();
+        assert_eq!(on_paths.len(), 8);
+        assert_eq!(all.predecessors(&'m'), &['a', 'b']);
+        assert!(all.predecessors(&'s').is_empty());
+    }
+
+    #[test]
+    fn stores_costs_densely() {
+        let index = |node: &char| (*node as u8 - b'a') as usize;
+        let search = dijkstra_with(Dense::new(4, index), ['a'], graph, |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(
+            ['a', 'b', 'c', 'd'].map(|node| search.cost(&node)),
+            [Some(0), Some(5), Some(2), Some(6)]
+        );
+        assert_eq!(search.path_to(&'d'), Some(vec!['a', 'c', 'b', 'd']));
+    }
+}
@@ -0,0 +1,363 @@
+use std::{fmt::Display, fs, path::Path, str::FromStr, time::Instant};
+
+use crate::bench::{Summary, Timings};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Expected part 1 or 2, got \"{}\"", s)),
+        }
+    }
+}
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/**
+Whether `part` is selected, where `None` selects both parts
+*/
+pub fn includes(part: Option<Part>, wanted: Part) -> bool {
+    part.is_none_or(|part| part == wanted)
+}
+
+/**
+Whether there's an input at `path` to solve. An empty file counts as missing, since that's the
+placeholder `aoc new` leaves to paste the real input into.
+*/
+pub fn has_input<P: AsRef<Path>>(path: P) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/**
+The outputs of each part that was solved
+*/
+pub type Outputs = (Option<String>, Option<String>);
+
+pub trait Solution: Sync {
+    /**
+    Solves `part`, or both parts if it's `None`, and reports how long it took. Solutions that can't
+    run the parts separately solve both anyway and only report a total.
+    */
+    fn solve_timed(&self, input_file: &str, part: Option<Part>) -> (Outputs, Timings);
+
+    fn solve(&self, input_file: &str) -> (String, String) {
+        match self.solve_timed(input_file, None).0 {
+            (Some(part_1_output), Some(part_2_output)) => (part_1_output, part_2_output),
+            _ => unreachable!("both parts were asked for"),
+        }
+    }
+}
+/**
+Adapter for tuple-style days, which do everything in one `get_program_output`
+*/
+impl<A: Display, B: Display> Solution for fn(&str) -> (A, B) {
+    fn solve_timed(&self, input_file: &str, _part: Option<Part>) -> (Outputs, Timings) {
+        let start = Instant::now();
+        let (part_1_output, part_2_output) = self(input_file);
+        let timings = Timings {
+            total: start.elapsed(),
+            ..Default::default()
+        };
+        (
+            (
+                Some(part_1_output.to_string()),
+                Some(part_2_output.to_string()),
+            ),
+            timings,
+        )
+    }
+}
+
+/**
+A day split into parsing and two parts that share the parsed input, so either part can run (and be
+timed) on its own
+*/
+pub struct Partwise<I, A, B> {
+    pub parse: fn(&str) -> I,
+    pub part_1: fn(&I) -> A,
+    pub part_2: fn(&I) -> B,
+}
+impl<I, A: Display, B: Display> Solution for Partwise<I, A, B> {
+    fn solve_timed(&self, input_file: &str, part: Option<Part>) -> (Outputs, Timings) {
+        let start = Instant::now();
+        let input = (self.parse)(input_file);
+        let parse = start.elapsed();
+        let timed = |solve: &dyn Fn() -> String| {
+            let start = Instant::now();
+            let output = solve();
+            (output, start.elapsed())
+        };
+        let part_1 =
+            includes(part, Part::One).then(|| timed(&|| (self.part_1)(&input).to_string()));
+        let part_2 =
+            includes(part, Part::Two).then(|| timed(&|| (self.part_2)(&input).to_string()));
+        let timings = Timings {
+            parse: Some(parse),
+            part_1: part_1.as_ref().map(|(_, duration)| *duration),
+            part_2: part_2.as_ref().map(|(_, duration)| *duration),
+            total: start.elapsed(),
+        };
+        (
+            (
+                part_1.map(|(output, _)| output),
+                part_2.map(|(output, _)| output),
+            ),
+            timings,
+        )
+    }
+}
+
+/**
+A single day registered with the runner. Built by `base_aoc!`, which exports one as `DAY` from every
+day crate.
+*/
+pub struct Day {
+    pub name: &'static str,
+    pub manifest_dir: &'static str,
+    pub solution: &'static dyn Solution,
+}
+impl Day {
+    /**
+    Year and day parsed from the crate name, so `ac_2024_09` and `ac_2021_9` both work
+    */
+    pub fn year_and_day(&self) -> (usize, usize) {
+        let mut numbers = self
+            .name
+            .trim_start_matches("ac_")
+            .split('_')
+            .map(|num| num.parse::<usize>().unwrap());
+        (numbers.next().unwrap(), numbers.next().unwrap())
+    }
+
+    pub fn year(&self) -> usize {
+        self.year_and_day().0
+    }
+
+    pub fn day(&self) -> usize {
+        self.year_and_day().1
+    }
+
+    pub fn input_path(&self) -> String {
+        format!("{}/input", self.manifest_dir)
+    }
+
+    pub fn answers_path(&self) -> String {
+        format!("{}/answers", self.manifest_dir)
+    }
+
+    /**
+    Every answer submitted for the day's real input, see `aoc submit`
+    */
+    pub fn submissions_path(&self) -> String {
+        format!("{}/submissions", self.manifest_dir)
+    }
+
+    pub fn solve(&self) -> (String, String) {
+        self.solve_file(&self.input_path())
+    }
+
+    /**
+    Solves some other input, like one of the examples
+    */
+    pub fn solve_file(&self, input_file: &str) -> (String, String) {
+        self.solution.solve(input_file)
+    }
+
+    pub fn solve_part(&self, input_file: &str, part: Option<Part>) -> Outputs {
+        self.solution.solve_timed(input_file, part).0
+    }
+
+    /**
+    Solves the real input `repeat` times (at least once), returning the outputs of the last run
+    */
+    pub fn bench(&self, repeat: usize, part: Option<Part>) -> (Outputs, Summary) {
+        let input_path = self.input_path();
+        let mut runs = vec![];
+        let mut outputs = None;
+        for _ in 0..repeat.max(1) {
+            let (run_outputs, timings) = self.solution.solve_timed(&input_path, part);
+            runs.push(timings);
+            outputs = Some(run_outputs);
+        }
+        (outputs.unwrap(), Summary::from_runs(self.name, &runs))
+    }
+}
+
+/**
+Command line options for the per-day binaries
+*/
+#[derive(Debug, PartialEq, Eq)]
+pub struct Options {
+    pub repeat: usize,
+    pub part: Option<Part>,
+}
+impl Options {
+    /**
+    Reads `[--repeat N] [--part 1|2]`, defaulting to a single run of both parts
+    */
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = Options {
+            repeat: 1,
+            part: None,
+        };
+        for pair in args.chunks(2) {
+            match pair {
+                [flag, repeat] if flag == "--repeat" => {
+                    options.repeat = repeat
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|repeat| *repeat > 0)
+                        .ok_or_else(|| {
+                            format!("Expected a positive number of runs, got \"{}\"", repeat)
+                        })?
+                }
+                [flag, part] if flag == "--part" => options.part = Some(part.parse()?),
+                _ => return Err("Usage: [--repeat N] [--part 1|2]".to_string()),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/**
+Prints whichever parts were solved, in the same format as `answers` files
+*/
+pub fn print_outputs((part_1_output, part_2_output): &Outputs) {
+    if let Some(part_1_output) = part_1_output {
+        println!("Part 1 output: {}", part_1_output);
+    }
+    if let Some(part_2_output) = part_2_output {
+        println!("Part 2 output: {}", part_2_output);
+    }
+}
+
+/**
+Entry point for the per-day binaries
+*/
+pub fn main(day: &Day) {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let options = Options::from_args(&args).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        std::process::exit(2);
+    });
+    let (outputs, summary) = day.bench(options.repeat, options.part);
+    print_outputs(&outputs);
+    for line in summary.describe() {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_program_output(input_file: &str) -> (usize, String) {
+        (input_file.len(), input_file.to_uppercase())
+    }
+
+    static DAY: Day = Day {
+        name: "ac_2024_09",
+        manifest_dir: "dir",
+        solution: &(get_program_output as fn(&str) -> (_, _)),
+    };
+
+    fn parse(input_file: &str) -> usize {
+        input_file.len()
+    }
+
+    fn part_1(input: &usize) -> usize {
+        *input
+    }
+
+    fn part_2(_input: &usize) -> &'static str {
+        panic!("part 2 shouldn't run")
+    }
+
+    static PARTWISE_DAY: Day = Day {
+        name: "ac_2024_10",
+        manifest_dir: "dir",
+        solution: &Partwise {
+            parse,
+            part_1,
+            part_2,
+        },
+    };
+
+    #[test]
+    fn year_and_day() {
+        assert_eq!(DAY.year_and_day(), (2024, 9));
+        assert_eq!(
+            Day {
+                name: "ac_2021_13",
+                ..DAY
+            }
+            .year_and_day(),
+            (2021, 13)
+        );
+    }
+
+    #[test]
+    fn solve() {
+        assert_eq!(DAY.solve(), ("9".to_string(), "DIR/INPUT".to_string()));
+        assert_eq!(
+            DAY.solve_part("input", Some(Part::Two)),
+            (Some("5".to_string()), Some("INPUT".to_string()))
+        );
+    }
+
+    #[test]
+    fn solve_one_part() {
+        assert_eq!(
+            PARTWISE_DAY.solve_part("input", Some(Part::One)),
+            (Some("5".to_string()), None)
+        );
+        let (outputs, summary) = PARTWISE_DAY.bench(2, Some(Part::One));
+        assert_eq!(outputs, (Some("9".to_string()), None));
+        assert!(summary.parse.is_some() && summary.part_1.is_some());
+        assert_eq!(summary.part_2, None);
+    }
+
+    #[test]
+    fn bench() {
+        let (outputs, summary) = DAY.bench(3, None);
+        let (part_1_output, part_2_output) = DAY.solve();
+        assert_eq!(outputs, (Some(part_1_output), Some(part_2_output)));
+        assert_eq!(summary.name, "ac_2024_09");
+        assert_eq!(summary.runs, 3);
+        assert_eq!(summary.parse, None);
+        assert!(summary.total.min <= summary.total.median);
+        assert_eq!(DAY.bench(0, None).1.runs, 1);
+    }
+
+    #[test]
+    fn options_from_args() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        let options = |repeat, part| Options { repeat, part };
+        assert_eq!(Options::from_args(&args(&[])), Ok(options(1, None)));
+        assert_eq!(
+            Options::from_args(&args(&["--repeat", "5"])),
+            Ok(options(5, None))
+        );
+        assert_eq!(
+            Options::from_args(&args(&["--part", "2", "--repeat", "3"])),
+            Ok(options(3, Some(Part::Two)))
+        );
+        assert!(Options::from_args(&args(&["--repeat", "0"])).is_err());
+        assert!(Options::from_args(&args(&["--repeat"])).is_err());
+        assert!(Options::from_args(&args(&["--part", "3"])).is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::{hash_map, HashMap};
+
+use crate::Point;
+
+/**
+A grid that only stores the cells that have something in them, for when the interesting points are
+few or spread over a huge or unknown area. With the default `()` values it's a set of points.
+*/
+#[derive(Debug, Clone)]
+pub struct SparseGrid<P, T = ()> {
+    cells: HashMap<P, T>,
+    bounds: Option<(P, P)>,
+}
+impl<P: Point, T: PartialEq> PartialEq for SparseGrid<P, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+impl<P: Point, T: Eq> Eq for SparseGrid<P, T> {}
+impl<P, T> Default for SparseGrid<P, T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+impl<P: Point, T> SparseGrid<P, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: P) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: P) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: P) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /**
+    Sets the cell at `point`, returning what was there before
+    */
+    pub fn insert(&mut self, point: P, value: T) -> Option<T> {
+        let (x, y) = point.components();
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => {
+                let ((min_x, min_y), (max_x, max_y)) = (min.components(), max.components());
+                (
+                    P::from_components(min_x.min(x), min_y.min(y)),
+                    P::from_components(max_x.max(x), max_y.max(y)),
+                )
+            }
+        });
+        self.cells.insert(point, value)
+    }
+
+    /**
+    Empties the cell at `point`. Removing a point on the edge of the bounding box shrinks it, which
+    means looking at every remaining point.
+    */
+    pub fn remove(&mut self, point: P) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        if let Some((min, max)) = self.bounds {
+            let (x, y) = point.components();
+            let ((min_x, min_y), (max_x, max_y)) = (min.components(), max.components());
+            if x == min_x || x == max_x || y == min_y || y == max_y {
+                self.bounds = bounds_of(self.cells.keys().copied());
+            }
+        }
+        Some(removed)
+    }
+
+    /**
+    The smallest and largest corners of the box around every point, or `None` if there are none
+    */
+    pub fn bounds(&self) -> Option<(P, P)> {
+        self.bounds
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = P> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /**
+    Every point with its value, in no particular order
+    */
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /**
+    Draws the bounding box row by row, using `glyph` for the stored cells and `empty` for the rest
+    */
+    pub fn render(&self, empty: char, glyph: impl Fn(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        let ((min_x, min_y), (max_x, max_y)) = (min.components(), max.components());
+        (min_y..=max_y)
+            .map(|y| {
+                (min_x..=max_x)
+                    .map(|x| self.get(P::from_components(x, y)).map_or(empty, &glyph))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+impl<P: Point> SparseGrid<P> {
+    /**
+    Adds `point` to the set, returning whether it was new
+    */
+    pub fn add(&mut self, point: P) -> bool {
+        self.insert(point, ()).is_none()
+    }
+}
+impl<P: Point, T> FromIterator<(P, T)> for SparseGrid<P, T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+impl<P: Point> FromIterator<P> for SparseGrid<P> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        iter.into_iter().map(|point| (point, ())).collect()
+    }
+}
+impl<P, T> IntoIterator for SparseGrid<P, T> {
+    type Item = (P, T);
+    type IntoIter = hash_map::IntoIter<P, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+fn bounds_of<P: Point>(points: impl Iterator<Item = P>) -> Option<(P, P)> {
+    points
+        .map(Point::components)
+        .fold(None, |bounds, (x, y)| match bounds {
+            None => Some(((x, y), (x, y))),
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                Some(((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))))
+            }
+        })
+        .map(|((min_x, min_y), (max_x, max_y))| {
+            (
+                P::from_components(min_x, min_y),
+                P::from_components(max_x, max_y),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{icoordinate::ICoordinate, Coordinate};
+
+    fn at(x: isize, y: isize) -> ICoordinate {
+        ICoordinate { x, y }
+    }
+
+    #[test]
+    fn tracks_bounds_through_negatives() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render('.', |_: &char| '#'), "");
+        grid.insert(at(1, 1), 'a');
+        grid.insert(at(-2, 0), 'b');
+        assert_eq!(grid.insert(at(1, 1), 'c'), Some('a'));
+        assert_eq!(grid.bounds(), Some((at(-2, 0), at(1, 1))));
+        assert_eq!(grid.render('.', |&c| c), "b...\n...c");
+        assert_eq!(grid.remove(at(-2, 0)), Some('b'));
+        assert_eq!(grid.bounds(), Some((at(1, 1), at(1, 1))));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn works_as_a_set() {
+        let mut dots: SparseGrid<Coordinate> = ["6,10", "0,14", "9,10"]
+            .into_iter()
+            .map(|s| s.parse::<Coordinate>().unwrap())
+            .collect();
+        assert!(!dots.add(Coordinate { x: 0, y: 14 }));
+        assert!(dots.add(Coordinate { x: 9, y: 14 }));
+        assert!(dots.contains(Coordinate { x: 6, y: 10 }));
+        assert_eq!(
+            dots.bounds(),
+            Some((Coordinate { x: 0, y: 10 }, Coordinate { x: 9, y: 14 }))
+        );
+        assert_eq!(dots.render(' ', |_| '#').lines().count(), 5);
+    }
+}
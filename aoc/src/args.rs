@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use advent_of_code_util::solution::{Day, Part};
+
+/**
+Command line arguments split into positional arguments and `--flag value` pairs
+*/
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct Args {
+    pub positional: Vec<String>,
+    pub flags: BTreeMap<String, String>,
+}
+impl Args {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(flag) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("Missing value for --{}", flag))?;
+                    parsed.flags.insert(flag.to_string(), value.clone());
+                }
+                None => parsed.positional.push(arg.clone()),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /**
+    Fails if any flag was passed that the command doesn't know about
+    */
+    pub fn only_flags(&self, known: &[&str]) -> Result<(), String> {
+        match self
+            .flags
+            .keys()
+            .find(|flag| !known.contains(&flag.as_str()))
+        {
+            Some(flag) => Err(format!("Unknown flag --{}", flag)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn number_flag(&self, flag: &str, default: usize) -> Result<usize, String> {
+        match self.flags.get(flag) {
+            Some(value) => parse_number(value),
+            None => Ok(default),
+        }
+    }
+
+    /**
+    The part selected with `--part`, or `None` for both
+    */
+    pub fn part(&self) -> Result<Option<Part>, String> {
+        self.flags.get("part").map(|part| part.parse()).transpose()
+    }
+}
+
+pub fn parse_number(arg: &str) -> Result<usize, String> {
+    arg.parse::<usize>()
+        .map_err(|_| format!("Expected a number, got \"{}\"", arg))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Selection {
+    All,
+    Year(usize),
+    Day(usize, usize),
+}
+impl Selection {
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        match args {
+            [all] if all == "all" => Ok(Selection::All),
+            [year] => Ok(Selection::Year(parse_number(year)?)),
+            [year, day] => Ok(Selection::Day(parse_number(year)?, parse_number(day)?)),
+            _ => Err("Expected <all | YEAR [DAY]>".to_string()),
+        }
+    }
+
+    pub fn matches(&self, day: &Day) -> bool {
+        match *self {
+            Selection::All => true,
+            Selection::Year(year) => day.year() == year,
+            Selection::Day(year, day_num) => day.year_and_day() == (year, day_num),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args() {
+        let parsed = Args::parse(&args(&["2024", "--repeat", "5", "17"])).unwrap();
+        assert_eq!(parsed.positional, args(&["2024", "17"]));
+        assert_eq!(parsed.number_flag("repeat", 1), Ok(5));
+        assert_eq!(parsed.number_flag("missing", 1), Ok(1));
+        assert!(parsed.only_flags(&["repeat"]).is_ok());
+        assert!(parsed.only_flags(&["dir"]).is_err());
+        assert!(Args::parse(&args(&["2024", "--repeat"])).is_err());
+        assert_eq!(parsed.part(), Ok(None));
+        assert_eq!(
+            Args::parse(&args(&["--part", "2"])).unwrap().part(),
+            Ok(Some(Part::Two))
+        );
+    }
+
+    #[test]
+    fn selection_from_args() {
+        assert_eq!(Selection::from_args(&args(&["all"])), Ok(Selection::All));
+        assert_eq!(
+            Selection::from_args(&args(&["2023"])),
+            Ok(Selection::Year(2023))
+        );
+        assert_eq!(
+            Selection::from_args(&args(&["2024", "09"])),
+            Ok(Selection::Day(2024, 9))
+        );
+        assert!(Selection::from_args(&args(&[])).is_err());
+        assert!(Selection::from_args(&args(&["twenty"])).is_err());
+        assert!(Selection::from_args(&args(&["2024", "1", "2"])).is_err());
+    }
+}
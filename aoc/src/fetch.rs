@@ -0,0 +1,230 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::http::{Http, Response};
+
+/**
+Environment variable holding the adventofcode.com session cookie
+*/
+pub const SESSION_VAR: &str = "SESSION_COOKIE";
+
+pub fn session_from_env() -> Result<String, String> {
+    std::env::var(SESSION_VAR)
+        .ok()
+        .filter(|session| !session.trim().is_empty())
+        .map(|session| session.trim().to_string())
+        .ok_or_else(|| {
+            format!(
+                "Set {} to your adventofcode.com session cookie",
+                SESSION_VAR
+            )
+        })
+}
+
+/**
+Inputs are cached outside the repo, so recreating a day or checking out a fresh clone doesn't
+download them again
+*/
+pub fn default_cache_dir() -> PathBuf {
+    let cache_home = std::env::var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(|_| std::env::temp_dir());
+    cache_home.join("aoc").join("inputs")
+}
+
+/**
+Where the time of the last request to the site is kept, next to the input cache, so separate runs
+can keep to the rate limit between them
+*/
+pub fn default_stamp_path() -> PathBuf {
+    default_cache_dir().with_file_name("last_request")
+}
+
+/**
+A short name for the account a session cookie belongs to, so each account's inputs are cached
+separately without the cookie itself ending up in a path. This is FNV-1a, which unlike the standard
+library's hasher gives the same answer from one build to the next.
+*/
+pub fn account_key(session: &str) -> String {
+    let hash = session.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/**
+Writes to a temporary file next to `path` and renames it into place, so an interrupted write never
+leaves half an input behind
+*/
+pub fn write_atomically(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Couldn't create {}: {}", parent.display(), err))?;
+    }
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".tmp");
+    fs::write(&temporary, contents)
+        .and_then(|_| fs::rename(&temporary, path))
+        .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))
+}
+
+/**
+adventofcode.com answers a missing or expired session with a page asking you to log in rather than
+an error status
+*/
+fn looks_like_html(body: &str) -> bool {
+    let start = body.trim_start().to_ascii_lowercase();
+    start.starts_with("<!doctype html") || start.starts_with("<html")
+}
+
+/**
+The input in a response, or why there isn't one
+*/
+pub fn check_input(response: Response) -> Result<String, String> {
+    match response.status {
+        200 if looks_like_html(&response.body) => Err(
+            "Got an HTML page instead of the input, the session cookie has probably expired"
+                .to_string(),
+        ),
+        200 if response.body.is_empty() => Err("Got an empty input".to_string()),
+        200 => Ok(response.body),
+        400 => Err("The session cookie was rejected".to_string()),
+        404 => Err("The puzzle isn't unlocked yet".to_string()),
+        status => Err(format!(
+            "Unexpected HTTP {}: {}",
+            status,
+            response.body.trim()
+        )),
+    }
+}
+
+pub struct Fetcher<'a> {
+    pub http: &'a dyn Http,
+    pub base_url: String,
+    pub session: String,
+    pub cache_dir: PathBuf,
+}
+impl Fetcher<'_> {
+    /**
+    Inputs differ between accounts, so they're kept per account
+    */
+    fn cache_path(&self, year: usize, day: usize) -> PathBuf {
+        self.cache_dir
+            .join(account_key(&self.session))
+            .join(year.to_string())
+            .join(format!("{:02}", day))
+    }
+
+    /**
+    A day's input from the cache, downloading it first if it isn't cached yet
+    */
+    pub fn input(&self, year: usize, day: usize) -> Result<String, String> {
+        let cache_path = self.cache_path(year, day);
+        if let Ok(input) = fs::read_to_string(&cache_path) {
+            return Ok(input);
+        }
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let input = check_input(self.http.get(&url, &self.session)?)?;
+        write_atomically(&cache_path, &input)?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::http::{test_server::TestServer, Client};
+
+    const LOGIN_PAGE: &str =
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<title>Advent of Code</title>";
+
+    #[test]
+    fn classifies_responses() {
+        let response = |status, body: &str| Response {
+            status,
+            body: body.to_string(),
+        };
+        assert_eq!(
+            check_input(response(200, "1\n2\n")),
+            Ok("1\n2\n".to_string())
+        );
+        assert!(check_input(response(200, LOGIN_PAGE))
+            .unwrap_err()
+            .contains("session cookie"));
+        assert!(check_input(response(200, "")).is_err());
+        assert!(check_input(response(400, "Puzzle inputs differ by user."))
+            .unwrap_err()
+            .contains("rejected"));
+        assert!(check_input(response(404, "Not Found"))
+            .unwrap_err()
+            .contains("unlocked"));
+        assert_eq!(
+            check_input(response(500, "oops\n")),
+            Err("Unexpected HTTP 500: oops".to_string())
+        );
+    }
+
+    #[test]
+    fn fetches_once_and_caches() {
+        let cache_dir = std::env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+        let server = TestServer::start(vec![(200, "1\n2\n"), (200, LOGIN_PAGE), (200, "3\n")]);
+        let client = Client::new(Duration::ZERO);
+        let fetcher = Fetcher {
+            http: &client,
+            base_url: format!("{}/", server.url),
+            session: "abc".to_string(),
+            cache_dir: cache_dir.clone(),
+        };
+
+        let first = fetcher.input(2024, 1);
+        let cached = fetcher.input(2024, 1);
+        let account_dir = cache_dir.join(account_key("abc"));
+        let cache_file = fs::read_to_string(account_dir.join("2024/01"));
+        let expired = fetcher.input(2024, 2);
+        let day_2_cached = account_dir.join("2024/02").exists();
+        let other_account = Fetcher {
+            session: "xyz".to_string(),
+            ..fetcher
+        }
+        .input(2024, 1);
+        let requests = server.requests();
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert_eq!(first, Ok("1\n2\n".to_string()));
+        assert_eq!(cached, first);
+        assert_eq!(cache_file.unwrap(), "1\n2\n");
+        assert!(expired.is_err());
+        assert!(!day_2_cached);
+        assert_eq!(other_account, Ok("3\n".to_string()));
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[1].starts_with("GET /2024/day/2/input "));
+        assert!(requests[2].contains("session=xyz"));
+        assert_ne!(account_key("abc"), account_key("xyz"));
+        assert!(!account_key("abc").contains("abc"));
+    }
+
+    #[test]
+    fn writes_atomically() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc_atomic_{}", std::process::id()))
+            .join("input");
+        write_atomically(&path, "old").unwrap();
+        write_atomically(&path, "new").unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        let leftovers = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(contents, "new");
+        assert_eq!(leftovers, 1);
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    cell::Cell,
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/**
+Identifies the runner to adventofcode.com, as its maintainer asks automated tools to do
+*/
+pub const USER_AGENT: &str = concat!(
+    "github.com/Markaronin/advent-of-code aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/**
+Time between requests, so fetching a whole year doesn't hammer the site
+*/
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/**
+The requests the runner makes, so fetching and submitting can be tested without the real site
+*/
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String>;
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    min_interval: Duration,
+    last_request: Cell<Option<SystemTime>>,
+    stamp_path: Option<PathBuf>,
+}
+impl Client {
+    /**
+    A client that only spaces out its own requests
+    */
+    pub fn new(min_interval: Duration) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            min_interval,
+            last_request: Cell::new(None),
+            stamp_path: None,
+        }
+    }
+
+    /**
+    Like `new`, but keeping the time of the last request in `stamp_path` too, so runs of the runner
+    one after another are spaced out as well
+    */
+    pub fn persistent(min_interval: Duration, stamp_path: PathBuf) -> Self {
+        Client {
+            stamp_path: Some(stamp_path),
+            ..Client::new(min_interval)
+        }
+    }
+
+    fn read_stamp(&self) -> Option<SystemTime> {
+        let millis = fs::read_to_string(self.stamp_path.as_ref()?).ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis.trim().parse().ok()?))
+    }
+
+    fn wait_for_rate_limit(&self) {
+        let last_request = self.last_request.get().max(self.read_stamp());
+        if let Some(last_request) = last_request {
+            // A clock that's gone backwards counts as no time having passed
+            let elapsed = last_request.elapsed().unwrap_or(Duration::ZERO);
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        let now = SystemTime::now();
+        self.last_request.set(Some(now));
+        if let Some(stamp_path) = &self.stamp_path {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            // Losing the stamp only loses the spacing for the next run, which isn't worth failing
+            // the request over
+            let _ = stamp_path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(stamp_path, millis.to_string()));
+        }
+    }
+}
+impl Http for Client {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        self.wait_for_rate_limit();
+        let result = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call();
+        into_response(result)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String> {
+        self.wait_for_rate_limit();
+        let result = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", session))
+            .send_form(form);
+        into_response(result)
+    }
+}
+
+/**
+Error statuses still have a body worth reading, so only transport failures are errors
+*/
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, String> {
+    match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => {
+            let status = response.status();
+            let body = response.into_string().map_err(|err| err.to_string())?;
+            Ok(Response { status, body })
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/**
+A stand-in for adventofcode.com that answers each connection with the next canned response and
+records the requests it got
+*/
+#[cfg(test)]
+pub mod test_server {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    pub struct TestServer {
+        pub url: String,
+        handle: JoinHandle<Vec<String>>,
+    }
+    impl TestServer {
+        pub fn start(responses: Vec<(u16, &'static str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let handle = thread::spawn(move || {
+                responses
+                    .into_iter()
+                    .map(|(status, body)| {
+                        let (stream, _) = listener.accept().unwrap();
+                        let mut reader = BufReader::new(stream);
+                        let mut request = String::new();
+                        let mut content_length = 0;
+                        loop {
+                            let mut line = String::new();
+                            reader.read_line(&mut line).unwrap();
+                            if let Some((name, value)) = line.split_once(':')
+                                && name.eq_ignore_ascii_case("content-length")
+                            {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                            request.push_str(&line);
+                            if line == "\r\n" || line.is_empty() {
+                                break;
+                            }
+                        }
+                        let mut request_body = vec![0; content_length];
+                        reader.read_exact(&mut request_body).unwrap();
+                        request.push_str(&String::from_utf8(request_body).unwrap());
+                        write!(
+                            reader.get_mut(),
+                            "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            status,
+                            body.len(),
+                            body
+                        )
+                        .unwrap();
+                        request
+                    })
+                    .collect()
+            });
+            TestServer { url, handle }
+        }
+
+        /**
+        Waits for every canned response to be used, then returns the raw requests
+        */
+        pub fn requests(self) -> Vec<String> {
+            self.handle.join().unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, time::Instant};
+
+    use super::{test_server::TestServer, *};
+
+    #[test]
+    fn sends_session_and_user_agent() {
+        let server = TestServer::start(vec![(200, "1\n2\n"), (404, "Not yet")]);
+        let client = Client::new(Duration::from_millis(50));
+        let start = Instant::now();
+        let ok = client.get(&format!("{}/2024/day/1/input", server.url), "abc");
+        let not_found = client.get(&format!("{}/2024/day/2/input", server.url), "abc");
+        let elapsed = start.elapsed();
+        let requests = server.requests();
+
+        assert_eq!(
+            ok,
+            Ok(Response {
+                status: 200,
+                body: "1\n2\n".to_string()
+            })
+        );
+        assert_eq!(not_found.unwrap().status, 404);
+        assert!(elapsed >= Duration::from_millis(50));
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("session=abc"));
+        assert!(requests[0].contains(USER_AGENT));
+    }
+
+    #[test]
+    fn spaces_out_separate_clients_sharing_a_stamp() {
+        let stamp_path =
+            std::env::temp_dir().join(format!("aoc_stamp_{}/last_request", std::process::id()));
+        let server = TestServer::start(vec![(200, "1"), (200, "2")]);
+        let url = format!("{}/2024/day/1/input", server.url);
+        let start = Instant::now();
+        let first =
+            Client::persistent(Duration::from_millis(200), stamp_path.clone()).get(&url, "abc");
+        let second =
+            Client::persistent(Duration::from_millis(200), stamp_path.clone()).get(&url, "abc");
+        let elapsed = start.elapsed();
+        server.requests();
+        let stamped = stamp_path.exists();
+        fs::remove_dir_all(stamp_path.parent().unwrap()).unwrap();
+
+        assert!(first.is_ok() && second.is_ok());
+        assert!(stamped);
+        assert!(elapsed >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn posts_forms() {
+        let server = TestServer::start(vec![(200, "<article>Thanks</article>")]);
+        let client = Client::new(Duration::ZERO);
+        let response = client.post_form(
+            &format!("{}/2024/day/1/answer", server.url),
+            "abc",
+            &[("level", "1"), ("answer", "12 34")],
+        );
+        let requests = server.requests();
+
+        assert_eq!(response.unwrap().body, "<article>Thanks</article>");
+        assert!(requests[0].starts_with("POST /2024/day/1/answer HTTP/1.1"));
+        assert!(requests[0].contains("session=abc"));
+        assert!(requests[0].ends_with("level=1&answer=12+34"));
+    }
+
+    #[test]
+    fn transport_failures_are_errors() {
+        let unused_port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let client = Client::new(Duration::ZERO);
+        assert!(client
+            .get(&format!("http://{}/", unused_port), "abc")
+            .is_err());
+    }
+}
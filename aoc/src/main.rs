@@ -0,0 +1,538 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use advent_of_code_util::{
+    answers::{verify, Answers, Verification},
+    bench::{format_table, read_previous_medians, write_summaries},
+    params::{with_overrides, Overrides},
+    solution::{has_input, includes, print_outputs, Day, Part},
+};
+use args::{parse_number, Args, Selection};
+use fetch::{default_cache_dir, default_stamp_path, session_from_env, write_atomically, Fetcher};
+use http::{Client, DEFAULT_BASE_URL, DEFAULT_MIN_INTERVAL};
+use itertools::Itertools;
+use scaffold::{dependency_lines, NewDay, Template};
+use submit::{Attempt, Log, Outcome, Submitter};
+
+mod args;
+mod fetch;
+mod http;
+mod registry;
+mod scaffold;
+mod submit;
+
+const USAGE: &str = "Usage:
+    aoc run <all | YEAR [DAY]> [--part 1|2] [--params NAME=VALUE,...] [--input FILE]
+    aoc bench <all | YEAR [DAY]> [--part 1|2] [--repeat N] [--dir DIR]
+    aoc verify <all | YEAR [DAY]>
+    aoc record <all | YEAR [DAY]> [--part 1|2]
+    aoc new YEAR DAY [--template lines|chars|blocks|digits] [--deps DEP,...]
+    aoc fetch <all | YEAR [DAY]> [--base-url URL] [--cache DIR]
+    aoc submit YEAR DAY PART [--answer ANSWER] [--base-url URL]";
+
+const REPO_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+const DEFAULT_BENCH_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../benchmarks");
+
+fn selected_days(selection: Selection) -> Result<Vec<&'static Day>, String> {
+    let days = registry::DAYS
+        .iter()
+        .copied()
+        .filter(|day| selection.matches(day))
+        .sorted_by_key(|day| day.year_and_day())
+        .collect_vec();
+    if days.is_empty() {
+        Err(format!("No registered days match {:?}", selection))
+    } else {
+        Ok(days)
+    }
+}
+
+/**
+Runs `f` for a day, reporting a missing input or a panicking solution instead of stopping
+*/
+fn try_day<T>(day: &Day, input_path: &str, f: impl FnOnce() -> T) -> Option<T> {
+    if !has_input(input_path) {
+        println!("Skipped {}: no input at {}", day.name, input_path);
+        return None;
+    }
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => Some(result),
+        Err(_) => {
+            println!("Failed {}: solution panicked", day.name);
+            None
+        }
+    }
+}
+
+/**
+Runs `part` (or both parts) of every selected day with `overrides` applied to their parameters,
+carrying on past failures. `input` replaces the real input, and only makes sense for a single day.
+Returns whether every day produced output.
+*/
+fn run(days: &[&Day], part: Option<Part>, overrides: &Overrides, input: Option<&str>) -> bool {
+    let mut all_succeeded = true;
+    for day in days {
+        let (year, day_num) = day.year_and_day();
+        println!("{} day {}", year, day_num);
+        let input_path = input
+            .map(str::to_string)
+            .unwrap_or_else(|| day.input_path());
+        match try_day(day, &input_path, || {
+            with_overrides(overrides, || day.solve_part(&input_path, part))
+        }) {
+            Some(Ok(outputs)) => print_outputs(&outputs),
+            Some(Err(message)) => {
+                println!("Failed {}: {}", day.name, message);
+                all_succeeded = false;
+            }
+            None => all_succeeded = false,
+        }
+    }
+    all_succeeded
+}
+
+/**
+Times every selected day, then prints and saves one summary table per year. Each table is compared
+against the one saved by the previous run with the same `part`, so timing a single part never gets
+compared with timing both. Only days registered with `parts(...)` report parse and per-part times;
+tuple-style days only have a total.
+*/
+fn bench(days: &[&Day], part: Option<Part>, repeat: usize, dir: &Path) -> bool {
+    let mut all_succeeded = true;
+    for (year, year_days) in &days.iter().group_by(|day| day.year()) {
+        let summaries = year_days
+            .filter_map(|day| {
+                let summary = try_day(day, &day.input_path(), || day.bench(repeat, part).1);
+                all_succeeded &= summary.is_some();
+                summary
+            })
+            .collect_vec();
+        if summaries.is_empty() {
+            continue;
+        }
+        let path = match part {
+            None => dir.join(format!("{}.tsv", year)),
+            Some(part) => dir.join(format!("{}_part_{}.tsv", year, part)),
+        };
+        let previous = read_previous_medians(&path);
+        println!("{}", year);
+        println!("{}", format_table(&summaries, &previous));
+        if let Err(err) = write_summaries(&path, &summaries) {
+            eprintln!("Couldn't write {}: {}", path.display(), err);
+            all_succeeded = false;
+        }
+    }
+    all_succeeded
+}
+
+/**
+Checks every selected day against its answers file. Days without an input or answers are skipped,
+only mismatches (or panics) count as failures.
+*/
+fn verify_all(days: &[&Day]) -> bool {
+    let mut all_succeeded = true;
+    for day in days {
+        match panic::catch_unwind(AssertUnwindSafe(|| verify(day))) {
+            Ok(Verification::MissingInput) => println!("Skipped {}: no input", day.name),
+            Ok(Verification::MissingAnswers) => println!("Skipped {}: no answers", day.name),
+            Ok(Verification::Verified) => println!("Verified {}", day.name),
+            Ok(Verification::Mismatched(mismatches)) => {
+                println!("Failed {}:", day.name);
+                for mismatch in mismatches {
+                    println!("    {}", mismatch);
+                }
+                all_succeeded = false;
+            }
+            Err(_) => {
+                println!("Failed {}: solution panicked", day.name);
+                all_succeeded = false;
+            }
+        }
+    }
+    all_succeeded
+}
+
+/**
+Solves every selected day that doesn't have an answers file yet and saves the outputs as its
+accepted answers. Existing answers are never overwritten. Recording a single `part` leaves the
+other one for later, when it's too slow or not solved yet.
+*/
+fn record(days: &[&Day], part: Option<Part>) -> bool {
+    let mut all_succeeded = true;
+    for day in days {
+        if Path::new(&day.answers_path()).exists() {
+            println!("Skipped {}: already has answers", day.name);
+            continue;
+        }
+        let Some((part_1, part_2)) = try_day(day, &day.input_path(), || {
+            day.solve_part(&day.input_path(), part)
+        }) else {
+            all_succeeded = false;
+            continue;
+        };
+        // Tuple-style days solve both parts regardless, but only the selected one is accepted
+        let answers = Answers {
+            part_1: part_1.filter(|_| includes(part, Part::One)),
+            part_2: part_2.filter(|_| includes(part, Part::Two)),
+        };
+        match std::fs::write(day.answers_path(), answers.to_string()) {
+            Ok(()) => println!("Recorded {}", day.name),
+            Err(err) => {
+                eprintln!("Couldn't write {}: {}", day.answers_path(), err);
+                all_succeeded = false;
+            }
+        }
+    }
+    all_succeeded
+}
+
+/**
+Downloads the input of every selected day that doesn't have one yet
+*/
+fn fetch(days: &[&Day], fetcher: &Fetcher) -> bool {
+    let mut all_succeeded = true;
+    for day in days {
+        let (year, day_num) = day.year_and_day();
+        let result = fetcher
+            .input(year, day_num)
+            .and_then(|input| write_atomically(Path::new(&day.input_path()), &input));
+        match result {
+            Ok(()) => println!("Fetched {}", day.name),
+            Err(message) => {
+                println!("Failed {}: {}", day.name, message);
+                all_succeeded = false;
+            }
+        }
+    }
+    all_succeeded
+}
+
+/**
+Submits one part of a day, solving the real input unless `answer` is given. Answers ruled out by
+the day's earlier submissions aren't sent, and a correct one is saved to its answers file. Returns
+whether the answer was correct.
+*/
+fn submit(
+    day: &Day,
+    part: Part,
+    answer: Option<String>,
+    submitter: &Submitter,
+) -> Result<bool, String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let Some((part_1, part_2)) = try_day(day, &day.input_path(), || {
+                day.solve_part(&day.input_path(), Some(part))
+            }) else {
+                return Ok(false);
+            };
+            match part {
+                Part::One => part_1,
+                Part::Two => part_2,
+            }
+            .ok_or_else(|| format!("Part {} wasn't solved", part))?
+        }
+    };
+    let answer = answer.trim().to_string();
+    if let Err(reason) = Log::read(day.submissions_path())?.check(part, &answer) {
+        println!("Not submitting {}: {}", answer, reason);
+        return Ok(false);
+    }
+
+    let (year, day_num) = day.year_and_day();
+    println!("Submitting {} for {} part {}", answer, day.name, part);
+    let (outcome, message) = submitter.submit(year, day_num, part, &answer)?;
+    println!("{}: {}", outcome, message);
+    Log::append(
+        day.submissions_path(),
+        &Attempt {
+            part,
+            outcome,
+            answer: answer.clone(),
+        },
+    )?;
+    if outcome != Outcome::Correct {
+        return Ok(false);
+    }
+    let mut answers = Answers::read(day.answers_path())
+        .transpose()?
+        .unwrap_or_default();
+    match part {
+        Part::One => answers.part_1 = Some(answer),
+        Part::Two => answers.part_2 = Some(answer),
+    }
+    std::fs::write(day.answers_path(), answers.to_string())
+        .map_err(|err| format!("Couldn't write {}: {}", day.answers_path(), err))?;
+    println!("Recorded {}", day.name);
+    Ok(true)
+}
+
+fn dispatch(args: &[String]) -> Result<bool, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err(USAGE.to_string());
+    };
+    let args = Args::parse(rest)?;
+    match command.as_str() {
+        "run" => {
+            args.only_flags(&["part", "params", "input"])?;
+            let days = selected_days(Selection::from_args(&args.positional)?)?;
+            let overrides = match args.flags.get("params") {
+                Some(params) => params.parse()?,
+                None => Overrides::default(),
+            };
+            let input = args.flags.get("input").map(String::as_str);
+            if input.is_some() && days.len() != 1 {
+                return Err("--input needs a single day".to_string());
+            }
+            Ok(run(&days, args.part()?, &overrides, input))
+        }
+        "bench" => {
+            args.only_flags(&["part", "repeat", "dir"])?;
+            let days = selected_days(Selection::from_args(&args.positional)?)?;
+            let repeat = args.number_flag("repeat", 1)?.max(1);
+            let dir = args
+                .flags
+                .get("dir")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_BENCH_DIR));
+            Ok(bench(&days, args.part()?, repeat, &dir))
+        }
+        "verify" => {
+            args.only_flags(&[])?;
+            let days = selected_days(Selection::from_args(&args.positional)?)?;
+            Ok(verify_all(&days))
+        }
+        "record" => {
+            args.only_flags(&["part"])?;
+            let days = selected_days(Selection::from_args(&args.positional)?)?;
+            Ok(record(&days, args.part()?))
+        }
+        "new" => {
+            args.only_flags(&["template", "deps"])?;
+            let Selection::Day(year, day) = Selection::from_args(&args.positional)? else {
+                return Err("Expected YEAR DAY".to_string());
+            };
+            let new_day = NewDay {
+                year,
+                day,
+                template: match args.flags.get("template") {
+                    Some(template) => template.parse()?,
+                    None => Template::Lines,
+                },
+                dependencies: dependency_lines(args.flags.get("deps").map_or("", String::as_str))?,
+            };
+            let root = Path::new(REPO_DIR)
+                .canonicalize()
+                .map_err(|err| err.to_string())?;
+            let dir = new_day.create(&root, registry::DAYS)?;
+            println!("Created {}", dir.display());
+            println!(
+                "Paste the example into testinput, then rebuild and run `aoc fetch {} {}`",
+                year, day
+            );
+            Ok(true)
+        }
+        "fetch" => {
+            args.only_flags(&["base-url", "cache"])?;
+            let days = selected_days(Selection::from_args(&args.positional)?)?;
+            let (with_input, missing): (Vec<_>, Vec<_>) = days
+                .into_iter()
+                .partition(|day| has_input(day.input_path()));
+            for day in with_input {
+                println!("Skipped {}: already has input", day.name);
+            }
+            if missing.is_empty() {
+                return Ok(true);
+            }
+            let client = Client::persistent(DEFAULT_MIN_INTERVAL, default_stamp_path());
+            let fetcher = Fetcher {
+                http: &client,
+                base_url: args
+                    .flags
+                    .get("base-url")
+                    .cloned()
+                    .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+                session: session_from_env()?,
+                cache_dir: args
+                    .flags
+                    .get("cache")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(default_cache_dir),
+            };
+            Ok(fetch(&missing, &fetcher))
+        }
+        "submit" => {
+            args.only_flags(&["answer", "base-url"])?;
+            let [year, day, part] = args.positional.as_slice() else {
+                return Err("Expected YEAR DAY PART".to_string());
+            };
+            let days = selected_days(Selection::Day(parse_number(year)?, parse_number(day)?))?;
+            let client = Client::persistent(DEFAULT_MIN_INTERVAL, default_stamp_path());
+            let submitter = Submitter {
+                http: &client,
+                base_url: args
+                    .flags
+                    .get("base-url")
+                    .cloned()
+                    .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+                session: session_from_env()?,
+            };
+            let result = submit(
+                days[0],
+                part.parse()?,
+                args.flags.get("answer").cloned(),
+                &submitter,
+            );
+            Ok(result.unwrap_or_else(|message| {
+                println!("Failed {}: {}", days[0].name, message);
+                false
+            }))
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect_vec();
+    match dispatch(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_unique_and_ordered_by_selection() {
+        let all = selected_days(Selection::All).unwrap();
+        assert_eq!(all.len(), registry::DAYS.len());
+        assert!(all.iter().map(|day| day.year_and_day()).all_unique());
+        assert!(all
+            .windows(2)
+            .all(|pair| pair[0].year_and_day() < pair[1].year_and_day()));
+
+        let year = selected_days(Selection::Year(2021)).unwrap();
+        assert!(year.iter().all(|day| day.year() == 2021));
+
+        let single = selected_days(Selection::Day(2024, 17)).unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].name, "ac_2024_17");
+
+        assert!(selected_days(Selection::Year(2015)).is_err());
+    }
+
+    #[test]
+    fn registry_covers_every_day_in_the_workspace() {
+        let workspace_days = std::fs::read_dir(REPO_DIR)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.join("Cargo.toml").exists())
+            .filter_map(|path| Some(path.file_name()?.to_str()?.to_string()))
+            .filter(|name| name.starts_with("ac_"))
+            .sorted()
+            .collect_vec();
+        let registered = registry::DAYS
+            .iter()
+            .map(|day| day.name.to_string())
+            .sorted()
+            .collect_vec();
+        assert_eq!(registered, workspace_days);
+    }
+
+    #[test]
+    fn bench_writes_a_table_per_year() {
+        let dir = std::env::temp_dir().join(format!("aoc_bench_dir_{}", std::process::id()));
+        let days = selected_days(Selection::Day(2022, 1)).unwrap();
+        assert!(bench(&days, None, 2, &dir));
+        let previous = read_previous_medians(dir.join("2022.tsv"));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(previous.keys().collect_vec(), vec!["ac_2022_1"]);
+    }
+
+    #[test]
+    fn bench_keeps_single_parts_apart() {
+        let dir = std::env::temp_dir().join(format!("aoc_bench_part_{}", std::process::id()));
+        let days = selected_days(Selection::Day(2022, 1)).unwrap();
+        assert!(bench(&days, Some(Part::Two), 1, &dir));
+        let full_runs = read_previous_medians(dir.join("2022.tsv"));
+        let part_runs = read_previous_medians(dir.join("2022_part_2.tsv"));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(full_runs.is_empty());
+        assert_eq!(part_runs.keys().collect_vec(), vec!["ac_2022_1"]);
+    }
+
+    #[test]
+    fn run_overrides_params_and_input() {
+        let days = selected_days(Selection::Day(2024, 18)).unwrap();
+        let example = concat!(env!("CARGO_MANIFEST_DIR"), "/../ac_2024_18/testinput");
+        let overrides = "size=7,part_1_bytes=12".parse().unwrap();
+        assert!(run(&days, None, &overrides, Some(example)));
+    }
+
+    fn get_program_output(input_file: &str) -> (usize, usize) {
+        let input = std::fs::read_to_string(input_file).unwrap();
+        (input.len(), input.lines().count())
+    }
+
+    #[test]
+    fn submit_logs_attempts_and_records_correct_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc_submit_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("input"), "ab\ncd\n").unwrap();
+        let day = Day {
+            name: "ac_2024_09",
+            manifest_dir: Box::leak(dir.to_str().unwrap().to_string().into_boxed_str()),
+            solution: &(get_program_output as fn(&str) -> (_, _)),
+        };
+        let server = http::test_server::TestServer::start(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+            ),
+        ]);
+        let client = Client::new(std::time::Duration::ZERO);
+        let submitter = Submitter {
+            http: &client,
+            base_url: server.url.clone(),
+            session: "abc".to_string(),
+        };
+
+        let too_high = submit(&day, Part::One, Some("100".to_string()), &submitter);
+        let higher = submit(&day, Part::One, Some("101".to_string()), &submitter);
+        let solved = submit(&day, Part::One, None, &submitter);
+        let again = submit(&day, Part::One, None, &submitter);
+        let requests = server.requests();
+        let submissions = std::fs::read_to_string(day.submissions_path()).unwrap();
+        let answers = Answers::read(day.answers_path()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(too_high, Ok(false));
+        assert_eq!(higher, Ok(false));
+        assert_eq!(solved, Ok(true));
+        assert_eq!(again, Ok(false));
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].ends_with("level=1&answer=6"));
+        assert_eq!(submissions, "1\ttoo high\t100\n1\tcorrect\t6\n");
+        assert_eq!(answers.unwrap().part_1.as_deref(), Some("6"));
+    }
+
+    #[test]
+    fn verify_checks_recorded_answers() {
+        let days = selected_days(Selection::Day(2022, 1)).unwrap();
+        assert!(Path::new(&days[0].answers_path()).exists());
+        assert!(verify_all(&days));
+    }
+}
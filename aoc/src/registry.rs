@@ -0,0 +1,110 @@
+use advent_of_code_util::solution::Day;
+
+pub static DAYS: &[&Day] = &[
+    &ac_2019_01::DAY,
+    &ac_2020_1::DAY,
+    &ac_2020_2::DAY,
+    &ac_2020_3::DAY,
+    &ac_2020_4::DAY,
+    &ac_2020_5::DAY,
+    &ac_2020_6::DAY,
+    &ac_2020_7::DAY,
+    &ac_2020_8::DAY,
+    &ac_2020_9::DAY,
+    &ac_2020_10::DAY,
+    &ac_2021_1::DAY,
+    &ac_2021_2::DAY,
+    &ac_2021_3::DAY,
+    &ac_2021_4::DAY,
+    &ac_2021_5::DAY,
+    &ac_2021_6::DAY,
+    &ac_2021_7::DAY,
+    &ac_2021_8::DAY,
+    &ac_2021_9::DAY,
+    &ac_2021_10::DAY,
+    &ac_2021_11::DAY,
+    &ac_2021_12::DAY,
+    &ac_2021_13::DAY,
+    &ac_2021_14::DAY,
+    &ac_2021_15::DAY,
+    &ac_2021_16::DAY,
+    &ac_2021_17::DAY,
+    &ac_2021_18::DAY,
+    &ac_2022_1::DAY,
+    &ac_2022_2::DAY,
+    &ac_2022_3::DAY,
+    &ac_2022_4::DAY,
+    &ac_2022_5::DAY,
+    &ac_2022_6::DAY,
+    &ac_2022_7::DAY,
+    &ac_2022_8::DAY,
+    &ac_2022_9::DAY,
+    &ac_2022_10::DAY,
+    &ac_2022_11::DAY,
+    &ac_2022_12::DAY,
+    &ac_2022_13::DAY,
+    &ac_2022_14::DAY,
+    &ac_2022_15::DAY,
+    &ac_2022_16::DAY,
+    &ac_2022_17::DAY,
+    &ac_2023_1::DAY,
+    &ac_2023_2::DAY,
+    &ac_2023_3::DAY,
+    &ac_2023_4::DAY,
+    &ac_2023_5::DAY,
+    &ac_2023_6::DAY,
+    &ac_2023_7::DAY,
+    &ac_2023_8::DAY,
+    &ac_2023_9::DAY,
+    &ac_2023_10::DAY,
+    &ac_2023_11::DAY,
+    &ac_2023_12::DAY,
+    &ac_2023_13::DAY,
+    &ac_2023_14::DAY,
+    &ac_2023_15::DAY,
+    &ac_2023_16::DAY,
+    &ac_2023_17::DAY,
+    &ac_2023_18::DAY,
+    &ac_2023_19::DAY,
+    &ac_2023_20::DAY,
+    &ac_2023_21::DAY,
+    &ac_2023_22::DAY,
+    &ac_2023_23::DAY,
+    &ac_2023_24::DAY,
+    &ac_2023_25::DAY,
+    &ac_2024_01::DAY,
+    &ac_2024_02::DAY,
+    &ac_2024_03::DAY,
+    &ac_2024_04::DAY,
+    &ac_2024_05::DAY,
+    &ac_2024_06::DAY,
+    &ac_2024_07::DAY,
+    &ac_2024_08::DAY,
+    &ac_2024_09::DAY,
+    &ac_2024_10::DAY,
+    &ac_2024_11::DAY,
+    &ac_2024_12::DAY,
+    &ac_2024_13::DAY,
+    &ac_2024_14::DAY,
+    &ac_2024_15::DAY,
+    &ac_2024_16::DAY,
+    &ac_2024_17::DAY,
+    &ac_2024_18::DAY,
+    &ac_2024_19::DAY,
+    &ac_2024_20::DAY,
+    &ac_2024_21::DAY,
+    &ac_2024_22::DAY,
+    &ac_2024_24::DAY,
+    &ac_2025_01::DAY,
+    &ac_2025_02::DAY,
+    &ac_2025_03::DAY,
+    &ac_2025_04::DAY,
+    &ac_2025_05::DAY,
+    &ac_2025_06::DAY,
+    &ac_2025_07::DAY,
+    &ac_2025_08::DAY,
+    &ac_2025_09::DAY,
+    &ac_2025_10::DAY,
+    &ac_2025_11::DAY,
+    &ac_2025_12::DAY,
+];
@@ -0,0 +1,251 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use advent_of_code_util::solution::Day;
+use itertools::Itertools;
+
+/**
+Dependencies that are declared once in the workspace manifest, so new days just opt in
+*/
+const WORKSPACE_DEPENDENCIES: &[&str] = &["itertools", "petgraph", "rand", "regex"];
+
+/**
+Which parse helper the new day starts out with
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Template {
+    Lines,
+    Chars,
+    Blocks,
+    Digits,
+}
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lines" => Ok(Template::Lines),
+            "chars" => Ok(Template::Chars),
+            "blocks" => Ok(Template::Blocks),
+            "digits" => Ok(Template::Digits),
+            _ => Err(format!(
+                "Unknown template \"{}\", expected lines, chars, blocks or digits",
+                s
+            )),
+        }
+    }
+}
+impl Template {
+    fn parse_function(&self) -> &'static str {
+        match self {
+            Template::Lines => "read_lines",
+            Template::Chars => "read_lines_of_chars",
+            Template::Blocks => "read_blocks",
+            Template::Digits => "read_grid_of_digits",
+        }
+    }
+
+    fn lib_rs(&self) -> String {
+        format!(
+            "use advent_of_code_util::{{base_aoc, parse::{parse}}};
+
+fn get_program_output(input_file: &str) -> (usize, usize) {{
+    let input = {parse}(input_file);
+
+    (input.len(), 0)
+}}
+
+base_aoc!(0, 0);
+",
+            parse = self.parse_function()
+        )
+    }
+}
+
+/**
+Crate names always zero-pad the day, so they sort properly
+*/
+pub fn crate_name(year: usize, day: usize) -> String {
+    format!("ac_{}_{:02}", year, day)
+}
+
+/**
+A manifest line for each dependency, given either as a workspace dependency's name or as
+`name@version`
+*/
+pub fn dependency_lines(dependencies: &str) -> Result<Vec<String>, String> {
+    std::iter::once("itertools")
+        .chain(dependencies.split(',').filter(|dep| !dep.is_empty()))
+        .unique()
+        .map(|dep| match dep.split_once('@') {
+            Some((name, version)) => Ok(format!("{} = \"{}\"", name, version)),
+            None if WORKSPACE_DEPENDENCIES.contains(&dep) => {
+                Ok(format!("{}.workspace = true", dep))
+            }
+            None => Err(format!(
+                "{} isn't a workspace dependency, give its version as {}@VERSION",
+                dep, dep
+            )),
+        })
+        .collect()
+}
+
+pub struct NewDay {
+    pub year: usize,
+    pub day: usize,
+    pub template: Template,
+    pub dependencies: Vec<String>,
+}
+impl NewDay {
+    fn cargo_toml(&self, name: &str) -> String {
+        format!(
+            "[package]
+name = \"{}\"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+advent_of_code_util = {{path = \"../advent_of_code_util\"}}
+{}
+",
+            name,
+            self.dependencies.join("\n")
+        )
+    }
+
+    /**
+    Creates the day's crate under `root` and registers it with the runner. Refuses to touch a day
+    that already exists, whatever its crate happens to be called.
+    */
+    pub fn create(&self, root: &Path, registered: &[&Day]) -> Result<PathBuf, String> {
+        if !(2015..=2100).contains(&self.year) || !(1..=25).contains(&self.day) {
+            return Err(format!("No puzzle for {} day {}", self.year, self.day));
+        }
+        if let Some(existing) = registered
+            .iter()
+            .find(|day| day.year_and_day() == (self.year, self.day))
+        {
+            return Err(format!("{} already exists", existing.name));
+        }
+        let name = crate_name(self.year, self.day);
+        let dir = root.join(&name);
+        if dir.exists() {
+            return Err(format!("{} already exists", dir.display()));
+        }
+
+        let write = |path: PathBuf, contents: &str| {
+            fs::write(&path, contents)
+                .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))
+        };
+        fs::create_dir_all(dir.join("src"))
+            .map_err(|err| format!("Couldn't create {}: {}", dir.display(), err))?;
+        write(dir.join("Cargo.toml"), &self.cargo_toml(&name))?;
+        write(dir.join("src/lib.rs"), &self.template.lib_rs())?;
+        write(
+            dir.join("src/main.rs"),
+            &format!(
+                "fn main() {{\n    advent_of_code_util::solution::main(&{}::DAY);\n}}\n",
+                name
+            ),
+        )?;
+        write(dir.join("input"), "")?;
+        write(dir.join("testinput"), "")?;
+
+        let runner_manifest = root.join("aoc/Cargo.toml");
+        let manifest = fs::read_to_string(&runner_manifest).map_err(|err| err.to_string())?;
+        write(
+            runner_manifest,
+            &format!(
+                "{}\n{} = {{path = \"../{}\"}}\n",
+                manifest.trim_end(),
+                name,
+                name
+            ),
+        )?;
+        let registry_path = root.join("aoc/src/registry.rs");
+        let registry = fs::read_to_string(&registry_path).map_err(|err| err.to_string())?;
+        let Some(end) = registry.rfind("];") else {
+            return Err(format!("No DAYS list in {}", registry_path.display()));
+        };
+        write(
+            registry_path,
+            &format!(
+                "{}    &{}::DAY,\n{}",
+                &registry[..end],
+                name,
+                &registry[end..]
+            ),
+        )?;
+        Ok(dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_and_dependencies() {
+        assert_eq!(crate_name(2024, 9), "ac_2024_09");
+        assert_eq!(crate_name(2021, 13), "ac_2021_13");
+        assert_eq!(
+            dependency_lines("regex,petgraph,foo@1.2"),
+            Ok(vec![
+                "itertools.workspace = true".to_string(),
+                "regex.workspace = true".to_string(),
+                "petgraph.workspace = true".to_string(),
+                "foo = \"1.2\"".to_string(),
+            ])
+        );
+        assert_eq!(dependency_lines("").unwrap().len(), 1);
+        assert!(dependency_lines("foo").is_err());
+        assert!("grid".parse::<Template>().is_err());
+    }
+
+    #[test]
+    fn creates_and_registers_a_day() {
+        let root = std::env::temp_dir().join(format!("aoc_new_{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "pub static DAYS: &[&Day] = &[\n    &ac_2024_01::DAY,\n];\n",
+        )
+        .unwrap();
+        let registered = crate::registry::DAYS;
+        let new_day = |year, day| NewDay {
+            year,
+            day,
+            template: Template::Chars,
+            dependencies: dependency_lines("regex").unwrap(),
+        };
+
+        let dir = new_day(2030, 3).create(&root, registered).unwrap();
+        let lib_rs = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        let cargo_toml = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        let runner_manifest = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        let registry = fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+        let placeholders = dir.join("input").exists() && dir.join("testinput").exists();
+        let again = new_day(2030, 3).create(&root, registered);
+        let existing = new_day(2021, 9).create(&root, registered);
+        let invalid = new_day(2030, 26).create(&root, registered);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(dir.ends_with("ac_2030_03"));
+        assert!(lib_rs.contains("let input = read_lines_of_chars(input_file);"));
+        // Every template reads into a `Vec`, and the stub has to use it to pass clippy
+        assert!(lib_rs.contains("(input.len(), 0)"));
+        assert!(
+            cargo_toml.contains("name = \"ac_2030_03\"") && cargo_toml.contains("regex.workspace")
+        );
+        assert!(runner_manifest.ends_with("ac_2030_03 = {path = \"../ac_2030_03\"}\n"));
+        assert!(registry.ends_with("    &ac_2024_01::DAY,\n    &ac_2030_03::DAY,\n];\n"));
+        assert!(placeholders);
+        assert!(again.is_err());
+        assert_eq!(existing, Err("ac_2021_9 already exists".to_string()));
+        assert!(invalid.is_err());
+    }
+}
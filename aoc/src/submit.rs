@@ -0,0 +1,384 @@
+use std::{fmt::Display, fs, io::Write, path::Path, str::FromStr};
+
+use advent_of_code_util::solution::Part;
+use itertools::Itertools;
+
+use crate::http::{Http, Response};
+
+/**
+What adventofcode.com made of a submitted answer
+*/
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    AlreadySolved,
+}
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too high" => Ok(Outcome::TooHigh),
+            "too low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "rate limited" => Ok(Outcome::RateLimited),
+            "already solved" => Ok(Outcome::AlreadySolved),
+            _ => Err(format!("Unknown outcome \"{}\"", s)),
+        }
+    }
+}
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let outcome = match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited => "rate limited",
+            Outcome::AlreadySolved => "already solved",
+        };
+        write!(f, "{}", outcome)
+    }
+}
+impl Outcome {
+    /**
+    Whether the answer itself was judged, rather than the submission being turned away
+    */
+    fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+        )
+    }
+}
+
+/**
+The text of the response's `<article>`, which is where the site explains itself
+*/
+pub fn article_text(body: &str) -> String {
+    let article = body
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or("", |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().join(" ")
+}
+
+pub fn classify(response: &Response) -> Result<Outcome, String> {
+    let text = article_text(&response.body);
+    if response.status != 200 || text.is_empty() {
+        return Err(format!(
+            "Unexpected HTTP {}, the session cookie has probably expired",
+            response.status
+        ));
+    }
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::RateLimited
+    } else if text.contains("Did you already complete it") {
+        Outcome::AlreadySolved
+    } else if text.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if text.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else {
+        return Err(format!("Unrecognised response: {}", text));
+    };
+    Ok(outcome)
+}
+
+/**
+One line of a day's `submissions` file, tab separated with the answer last
+*/
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Attempt {
+    pub part: Part,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+impl FromStr for Attempt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.splitn(3, '\t');
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(part), Some(outcome), Some(answer)) => Ok(Attempt {
+                part: part.parse()?,
+                outcome: outcome.parse()?,
+                answer: answer.to_string(),
+            }),
+            _ => Err(format!("Expected PART\tOUTCOME\tANSWER, got \"{}\"", s)),
+        }
+    }
+}
+impl Display for Attempt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t{}", self.part, self.outcome, self.answer)
+    }
+}
+
+/**
+Every answer submitted for a day, so the same mistake is never sent twice
+*/
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct Log(pub Vec<Attempt>);
+impl Log {
+    /**
+    Reads a submissions file, which is empty if there isn't one yet
+    */
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map(Log),
+            Err(_) if !path.as_ref().exists() => Ok(Log::default()),
+            Err(err) => Err(format!(
+                "Couldn't read {}: {}",
+                path.as_ref().display(),
+                err
+            )),
+        }
+    }
+
+    pub fn append<P: AsRef<Path>>(path: P, attempt: &Attempt) -> Result<(), String> {
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| writeln!(file, "{}", attempt))
+            .map_err(|err| format!("Couldn't write {}: {}", path.as_ref().display(), err))
+    }
+
+    /**
+    Why `answer` shouldn't be submitted for `part`, going by earlier attempts. A number at or past
+    an answer that was too high or too low is known to be wrong too.
+    */
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), String> {
+        if answer.is_empty() || answer.contains(['\t', '\n']) {
+            return Err(format!("\"{}\" isn't a single line answer", answer));
+        }
+        let judged = self
+            .0
+            .iter()
+            .filter(|attempt| attempt.part == part && attempt.outcome.is_verdict())
+            .collect_vec();
+        if let Some(correct) = judged
+            .iter()
+            .find(|attempt| attempt.outcome == Outcome::Correct)
+        {
+            return Err(format!(
+                "Part {} was already solved with {}",
+                part, correct.answer
+            ));
+        }
+        if let Some(previous) = judged.iter().find(|attempt| attempt.answer == answer) {
+            return Err(format!(
+                "{} was already submitted and was {}",
+                answer, previous.outcome
+            ));
+        }
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |outcome| {
+            judged
+                .iter()
+                .filter(move |attempt| attempt.outcome == outcome)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Outcome::TooHigh).min()
+            && number >= high
+        {
+            return Err(format!("{} is too high, {} already was", answer, high));
+        }
+        if let Some(low) = bound(Outcome::TooLow).max()
+            && number <= low
+        {
+            return Err(format!("{} is too low, {} already was", answer, low));
+        }
+        Ok(())
+    }
+}
+
+pub struct Submitter<'a> {
+    pub http: &'a dyn Http,
+    pub base_url: String,
+    pub session: String,
+}
+impl Submitter<'_> {
+    /**
+    Posts an answer, returning how it was judged along with the site's explanation
+    */
+    pub fn submit(
+        &self,
+        year: usize,
+        day: usize,
+        part: Part,
+        answer: &str,
+    ) -> Result<(Outcome, String), String> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let level = part.to_string();
+        let response = self.http.post_form(
+            &url,
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+        let outcome = classify(&response)?;
+        Ok((outcome, article_text(&response.body)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::http::{test_server::TestServer, Client};
+
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article>\n</main>";
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article>\n</main>";
+    const TOO_LOW: &str =
+        "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer. If you're stuck...</p></article>";
+    const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.</p></article>";
+    const ALREADY_SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+    fn attempt(part: Part, outcome: Outcome, answer: &str) -> Attempt {
+        Attempt {
+            part,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn classifies_responses() {
+        let response = |status, body: &str| Response {
+            status,
+            body: body.to_string(),
+        };
+        assert_eq!(classify(&response(200, CORRECT)), Ok(Outcome::Correct));
+        assert_eq!(classify(&response(200, TOO_HIGH)), Ok(Outcome::TooHigh));
+        assert_eq!(classify(&response(200, TOO_LOW)), Ok(Outcome::TooLow));
+        assert_eq!(classify(&response(200, WRONG)), Ok(Outcome::Wrong));
+        assert_eq!(
+            classify(&response(200, RATE_LIMITED)),
+            Ok(Outcome::RateLimited)
+        );
+        assert_eq!(
+            classify(&response(200, ALREADY_SOLVED)),
+            Ok(Outcome::AlreadySolved)
+        );
+        assert!(classify(&response(200, "<html><body>Log in</body></html>")).is_err());
+        assert!(classify(&response(400, "Bad Request")).is_err());
+        assert_eq!(
+            article_text(CORRECT),
+            "That's the right answer! You are one gold star closer."
+        );
+    }
+
+    #[test]
+    fn parses_and_displays_attempts() {
+        let line = "2\ttoo high\t1 2\t3";
+        let parsed = line.parse::<Attempt>().unwrap();
+        assert_eq!(parsed, attempt(Part::Two, Outcome::TooHigh, "1 2\t3"));
+        assert_eq!(parsed.to_string(), line);
+        assert!("1\tright\t5".parse::<Attempt>().is_err());
+        assert!("1 correct 5".parse::<Attempt>().is_err());
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = Log(vec![
+            attempt(Part::One, Outcome::TooHigh, "100"),
+            attempt(Part::One, Outcome::TooHigh, "150"),
+            attempt(Part::One, Outcome::TooLow, "10"),
+            attempt(Part::One, Outcome::Wrong, "abc"),
+            attempt(Part::One, Outcome::RateLimited, "50"),
+            attempt(Part::Two, Outcome::Correct, "7"),
+        ]);
+        assert_eq!(log.check(Part::One, "50"), Ok(()));
+        assert_eq!(log.check(Part::One, "abd"), Ok(()));
+        assert_eq!(
+            log.check(Part::One, "120"),
+            Err("120 is too high, 100 already was".to_string())
+        );
+        assert_eq!(
+            log.check(Part::One, "10"),
+            Err("10 was already submitted and was too low".to_string())
+        );
+        assert_eq!(
+            log.check(Part::One, "-3"),
+            Err("-3 is too low, 10 already was".to_string())
+        );
+        assert!(log.check(Part::One, "abc").is_err());
+        assert!(log.check(Part::One, "").is_err());
+        assert!(log.check(Part::One, "1\n2").is_err());
+        assert_eq!(
+            log.check(Part::Two, "8"),
+            Err("Part 2 was already solved with 7".to_string())
+        );
+    }
+
+    #[test]
+    fn reads_and_appends() {
+        let path = std::env::temp_dir().join(format!("aoc_submissions_{}", std::process::id()));
+        let empty = Log::read(&path);
+        Log::append(&path, &attempt(Part::One, Outcome::TooLow, "3")).unwrap();
+        Log::append(&path, &attempt(Part::One, Outcome::Correct, "4")).unwrap();
+        let log = Log::read(&path);
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(empty, Ok(Log::default()));
+        assert_eq!(contents, "1\ttoo low\t3\n1\tcorrect\t4\n");
+        assert_eq!(log.unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn submits_to_the_answer_page() {
+        let server = TestServer::start(vec![(200, TOO_HIGH), (400, "Bad Request")]);
+        let client = Client::new(Duration::ZERO);
+        let submitter = Submitter {
+            http: &client,
+            base_url: server.url.clone(),
+            session: "abc".to_string(),
+        };
+
+        let too_high = submitter.submit(2024, 3, Part::Two, "42");
+        let logged_out = submitter.submit(2024, 3, Part::Two, "41");
+        let requests = server.requests();
+
+        assert_eq!(too_high.unwrap().0, Outcome::TooHigh);
+        assert!(logged_out.is_err());
+        assert!(requests[0].starts_with("POST /2024/day/3/answer HTTP/1.1"));
+        assert!(requests[0].contains("session=abc"));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+    }
+}